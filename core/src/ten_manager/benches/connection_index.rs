@@ -0,0 +1,99 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+//! Compares the scan-based `graph_add_connection` path against the
+//! index-backed `graph_add_connection_indexed` path while wiring up a graph
+//! with many extensions, each connected to its predecessor. Run with
+//! `cargo bench -p ten_manager --bench connection_index`.
+use std::collections::HashMap;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use ten_manager::graph::connections::{add::graph_add_connection, index::ConnectionGraphIndex};
+use ten_rust::{
+    graph::{connection::GraphLoc, node::GraphNode, Graph},
+    pkg_info::message::MsgType,
+};
+
+fn build_graph_with_extensions(count: usize) -> Graph {
+    let mut graph = Graph {
+        nodes: (0..count)
+            .map(|i| GraphNode::new_extension_node(format!("ext_{}", i), "addon".to_string(), None, None, None))
+            .collect(),
+        connections: None,
+        exposed_messages: None,
+        exposed_properties: None,
+    };
+    graph.connections = Some(Vec::new());
+    graph
+}
+
+fn loc(name: &str) -> GraphLoc {
+    GraphLoc {
+        app: None,
+        extension: Some(name.to_string()),
+        subgraph: None,
+        selector: None,
+    }
+}
+
+fn bench_scan_path(c: &mut Criterion) {
+    let mut group = c.benchmark_group("graph_add_connection_scan");
+    for size in [32usize, 128, 512] {
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, &size| {
+            b.iter(|| {
+                let mut graph = build_graph_with_extensions(size);
+                let pkgs_cache = HashMap::new();
+                let rt = tokio::runtime::Runtime::new().unwrap();
+                for i in 1..size {
+                    rt.block_on(graph_add_connection(
+                        black_box(&mut graph),
+                        &None,
+                        &pkgs_cache,
+                        loc(&format!("ext_{}", i - 1)),
+                        loc(&format!("ext_{}", i)),
+                        MsgType::Cmd,
+                        vec!["cmd".to_string()],
+                        None,
+                    ))
+                    .unwrap();
+                }
+            });
+        });
+    }
+    group.finish();
+}
+
+fn bench_indexed_path(c: &mut Criterion) {
+    let mut group = c.benchmark_group("graph_add_connection_indexed");
+    for size in [32usize, 128, 512] {
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, &size| {
+            b.iter(|| {
+                let mut graph = build_graph_with_extensions(size);
+                let mut index = ConnectionGraphIndex::from_graph(&graph);
+                let pkgs_cache = HashMap::new();
+                let rt = tokio::runtime::Runtime::new().unwrap();
+                for i in 1..size {
+                    rt.block_on(ten_manager::graph::connections::add::graph_add_connection_indexed(
+                        black_box(&mut graph),
+                        &mut index,
+                        &None,
+                        &pkgs_cache,
+                        loc(&format!("ext_{}", i - 1)),
+                        loc(&format!("ext_{}", i)),
+                        MsgType::Cmd,
+                        vec!["cmd".to_string()],
+                        None,
+                    ))
+                    .unwrap();
+                }
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_scan_path, bench_indexed_path);
+criterion_main!(benches);
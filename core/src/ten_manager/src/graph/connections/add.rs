@@ -17,7 +17,11 @@ use ten_rust::{
     pkg_info::message::MsgType,
 };
 
-use super::validate::{validate_connection_schema, MsgConversionValidateInfo};
+use super::{
+    bloom::NameBloomFilter,
+    index::ConnectionGraphIndex,
+    validate::{validate_connection_schema, MsgConversionValidateInfo},
+};
 
 /// Helper function to add a message flow to a specific flow collection.
 fn add_to_flow(
@@ -85,9 +89,23 @@ fn check_connection_exists(
 
                 if let Some(flows) = msg_flows {
                     for flow in flows {
-                        // Check if message name matches.
+                        // A flow carrying a wide `names` list would
+                        // otherwise force every requested name to be
+                        // compared against every name on the flow. The
+                        // bloom filter lets a definitely-absent name skip
+                        // straight to the next flow; a possibly-present
+                        // result still falls back to the exact comparison
+                        // below.
+                        let name_filter = NameBloomFilter::from_flow(flow);
+
                         for name in msg_names.iter() {
-                            if flow.name.as_deref() == Some(name) {
+                            if !name_filter.might_contain(name) {
+                                continue;
+                            }
+
+                            let name_matches = flow.name.as_deref() == Some(name.as_str())
+                                || flow.names.as_ref().is_some_and(|names| names.contains(name));
+                            if name_matches {
                                 // Check if destination already exists.
                                 for dest_item in &flow.dest {
                                     if dest_item.loc.matches(dest) {
@@ -208,3 +226,278 @@ pub async fn graph_add_connection(
         }
     }
 }
+
+/// Checks if the connection already exists, consulting `index` instead of
+/// scanning every connection/flow/destination in `graph`.
+#[allow(clippy::too_many_arguments)]
+fn check_connection_exists_indexed(
+    index: &ConnectionGraphIndex,
+    src: &GraphLoc,
+    dest: &GraphLoc,
+    msg_type: &MsgType,
+    msg_names: &Vec<String>,
+) -> Result<()> {
+    for name in msg_names {
+        if index.contains_edge(src, *msg_type, name, dest) {
+            return Err(anyhow::anyhow!(
+                "Connection already exists: src: {:?} '{}', msg_type:{:?}, msg_name:{}, dest: \
+                 {:?} '{}'",
+                src.get_node_type()?,
+                src.get_node_name()?,
+                msg_type,
+                name,
+                dest.get_node_type()?,
+                dest.get_node_name()?,
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Same as [`graph_add_connection`], but consults and maintains a
+/// [`ConnectionGraphIndex`] alongside `graph` so duplicate-detection and
+/// connection lookup are O(1) instead of a linear scan. Intended for bulk
+/// wiring of a graph with many flows, where `index` is built once up front
+/// via [`ConnectionGraphIndex::from_graph`] and reused across every call.
+///
+/// `index` must describe `graph` exactly; passing an index built from a
+/// different (or stale) graph produces incorrect duplicate detection.
+#[allow(clippy::too_many_arguments)]
+pub async fn graph_add_connection_indexed(
+    graph: &mut Graph,
+    index: &mut ConnectionGraphIndex,
+    graph_app_base_dir: &Option<String>,
+    pkgs_cache: &HashMap<String, PkgsInfoInApp>,
+    src: GraphLoc,
+    dest: GraphLoc,
+    msg_type: MsgType,
+    msg_names: Vec<String>,
+    msg_conversion: Option<MsgAndResultConversion>,
+) -> Result<()> {
+    // Store the original state in case validation fails.
+    let original_graph = graph.clone();
+
+    // Check if nodes exist.
+    GraphLoc::check_node_exists(&src, graph)?;
+    GraphLoc::check_node_exists(&dest, graph)?;
+
+    // Check if connection already exists.
+    check_connection_exists_indexed(index, &src, &dest, &msg_type, &msg_names)?;
+
+    validate_connection_schema(
+        pkgs_cache,
+        graph,
+        graph_app_base_dir,
+        &MsgConversionValidateInfo {
+            src: &src,
+            dest: &dest,
+            msg_type: &msg_type,
+            msg_names: &msg_names,
+            msg_conversion: &msg_conversion,
+        },
+    )
+    .await?;
+
+    // Create destination object.
+    let destination = GraphDestination {
+        loc: dest.clone(),
+        msg_conversion,
+    };
+
+    // Initialize connections if None.
+    if graph.connections.is_none() {
+        graph.connections = Some(Vec::new());
+    }
+
+    // Create a message flow.
+    if msg_names.is_empty() {
+        return Err(anyhow::anyhow!("Message name is empty"));
+    }
+
+    let message_flow: GraphMessageFlow = if msg_names.len() == 1 {
+        GraphMessageFlow::new(Some(msg_names[0].clone()), None, vec![destination], vec![])
+    } else {
+        GraphMessageFlow::new(None, Some(msg_names.clone()), vec![destination], vec![])
+    };
+
+    // Get or create a connection for the source node and add the message
+    // flow.
+    let mut new_slot = None;
+    {
+        let connections = graph.connections.as_mut().unwrap();
+
+        // Find or create connection via the index instead of scanning. A
+        // newly created slot is only recorded in the index once validation
+        // succeeds below.
+        let connection_idx = if let Some(idx) = index.connection_slot(&src) {
+            idx
+        } else {
+            // Create a new connection for the source node.
+            connections.push(GraphConnection {
+                loc: src.clone(),
+                cmd: None,
+                data: None,
+                audio_frame: None,
+                video_frame: None,
+            });
+            let idx = connections.len() - 1;
+            new_slot = Some(idx);
+            idx
+        };
+
+        // Add the message flow to the appropriate collection.
+        let connection = &mut connections[connection_idx];
+        add_message_flow_to_connection(connection, &msg_type, message_flow)?;
+    }
+
+    // Validate the updated graph.
+    match graph.validate_and_complete(None) {
+        Ok(_) => {
+            // Keep the index in sync with the now-committed edit.
+            if let Some(idx) = new_slot {
+                index.record_connection_slot(src.clone(), idx);
+            }
+            for name in msg_names {
+                index.insert_edge(src.clone(), msg_type, name, dest.clone());
+            }
+            Ok(())
+        }
+        Err(e) => {
+            // Restore the original graph if validation fails. The index was
+            // only updated on success above, so it stays consistent with
+            // the restored graph without needing to be rebuilt.
+            *graph = original_graph;
+            Err(e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ten_rust::graph::node::GraphNode;
+
+    use super::*;
+
+    fn loc(name: &str) -> GraphLoc {
+        GraphLoc {
+            app: None,
+            extension: Some(name.to_string()),
+            subgraph: None,
+            selector: None,
+        }
+    }
+
+    fn graph_with_extensions(names: &[&str]) -> Graph {
+        Graph {
+            nodes: names
+                .iter()
+                .map(|name| GraphNode::new_extension_node(name.to_string(), "addon".to_string(), None, None, None))
+                .collect(),
+            connections: Some(Vec::new()),
+            exposed_messages: None,
+            exposed_properties: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_indexed_add_wires_a_connection_same_as_the_scan_path() {
+        let mut graph = graph_with_extensions(&["ext_a", "ext_b"]);
+        let mut index = ConnectionGraphIndex::from_graph(&graph);
+        let pkgs_cache = HashMap::new();
+
+        graph_add_connection_indexed(
+            &mut graph,
+            &mut index,
+            &None,
+            &pkgs_cache,
+            loc("ext_a"),
+            loc("ext_b"),
+            MsgType::Cmd,
+            vec!["hello".to_string()],
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(graph.connections.as_ref().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_indexed_add_rejects_duplicate_connection() {
+        let mut graph = graph_with_extensions(&["ext_a", "ext_b"]);
+        let mut index = ConnectionGraphIndex::from_graph(&graph);
+        let pkgs_cache = HashMap::new();
+
+        graph_add_connection_indexed(
+            &mut graph,
+            &mut index,
+            &None,
+            &pkgs_cache,
+            loc("ext_a"),
+            loc("ext_b"),
+            MsgType::Cmd,
+            vec!["hello".to_string()],
+            None,
+        )
+        .await
+        .unwrap();
+
+        let result = graph_add_connection_indexed(
+            &mut graph,
+            &mut index,
+            &None,
+            &pkgs_cache,
+            loc("ext_a"),
+            loc("ext_b"),
+            MsgType::Cmd,
+            vec!["hello".to_string()],
+            None,
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(graph.connections.as_ref().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_indexed_add_matches_scan_path_connection_count() {
+        let mut scan_graph = graph_with_extensions(&["ext_a", "ext_b", "ext_c"]);
+        let mut indexed_graph = graph_with_extensions(&["ext_a", "ext_b", "ext_c"]);
+        let mut index = ConnectionGraphIndex::from_graph(&indexed_graph);
+        let pkgs_cache = HashMap::new();
+
+        for (src, dest) in [("ext_a", "ext_b"), ("ext_b", "ext_c")] {
+            graph_add_connection(
+                &mut scan_graph,
+                &None,
+                &pkgs_cache,
+                loc(src),
+                loc(dest),
+                MsgType::Cmd,
+                vec!["hello".to_string()],
+                None,
+            )
+            .await
+            .unwrap();
+
+            graph_add_connection_indexed(
+                &mut indexed_graph,
+                &mut index,
+                &None,
+                &pkgs_cache,
+                loc(src),
+                loc(dest),
+                MsgType::Cmd,
+                vec!["hello".to_string()],
+                None,
+            )
+            .await
+            .unwrap();
+        }
+
+        assert_eq!(
+            scan_graph.connections.as_ref().unwrap().len(),
+            indexed_graph.connections.as_ref().unwrap().len()
+        );
+    }
+}
@@ -0,0 +1,177 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+use ten_rust::graph::connection::GraphMessageFlow;
+
+/// Murmur3_32 (the variant used by Bitcoin's BIP-37 `filterload` bloom
+/// filters), so [`NameBloomFilter`] membership tests are deterministic
+/// across runs.
+fn murmur3_32(data: &[u8], seed: u32) -> u32 {
+    const C1: u32 = 0xcc9e2d51;
+    const C2: u32 = 0x1b873593;
+
+    let mut hash = seed;
+    let chunks = data.chunks_exact(4);
+    let remainder = chunks.remainder();
+
+    for chunk in chunks {
+        let mut k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        k = k.wrapping_mul(C1);
+        k = k.rotate_left(15);
+        k = k.wrapping_mul(C2);
+
+        hash ^= k;
+        hash = hash.rotate_left(13);
+        hash = hash.wrapping_mul(5).wrapping_add(0xe6546b64);
+    }
+
+    if !remainder.is_empty() {
+        let mut k = 0u32;
+        for (i, byte) in remainder.iter().enumerate() {
+            k |= (*byte as u32) << (8 * i);
+        }
+        k = k.wrapping_mul(C1);
+        k = k.rotate_left(15);
+        k = k.wrapping_mul(C2);
+        hash ^= k;
+    }
+
+    hash ^= data.len() as u32;
+    hash ^= hash >> 16;
+    hash = hash.wrapping_mul(0x85ebca6b);
+    hash ^= hash >> 13;
+    hash = hash.wrapping_mul(0xc2b2ae35);
+    hash ^= hash >> 16;
+    hash
+}
+
+/// A per-flow message-name membership filter, modeled on Bitcoin's
+/// `filterload` connection filters: a bit array plus a hash-function count
+/// `k`. A negative [`NameBloomFilter::might_contain`] result is definitive,
+/// letting `check_connection_exists` skip a flow's exact-match comparison
+/// entirely; a positive result may be a false positive and must fall back to
+/// an exact string comparison.
+#[derive(Debug, Clone)]
+pub struct NameBloomFilter {
+    bits: Vec<bool>,
+    k: u32,
+    base_seed: u32,
+}
+
+impl NameBloomFilter {
+    /// Sizes `nbits`/`k` from `name_count` to target a ~1% false-positive
+    /// rate, following the standard bloom-filter sizing formulas:
+    /// `m = ceil(-(n * ln(p)) / ln(2)^2)`, `k = round((m / n) * ln(2))`.
+    fn size_for(name_count: usize) -> (usize, u32) {
+        let n = name_count.max(1) as f64;
+        const TARGET_FALSE_POSITIVE_RATE: f64 = 0.01;
+
+        let m = (-(n * TARGET_FALSE_POSITIVE_RATE.ln()) / std::f64::consts::LN_2.powi(2)).ceil();
+        let k = ((m / n) * std::f64::consts::LN_2).round().max(1.0);
+
+        (m.max(1.0) as usize, k as u32)
+    }
+
+    /// Builds a filter from an iterator of message names, e.g. a flow's
+    /// `name` (single) or `names` (multi) field.
+    pub fn from_names<'a>(names: impl Iterator<Item = &'a str> + Clone) -> Self {
+        let name_count = names.clone().count();
+        let (nbits, k) = Self::size_for(name_count);
+
+        let mut filter = Self {
+            bits: vec![false; nbits],
+            k,
+            base_seed: 0,
+        };
+        for name in names {
+            filter.insert(name);
+        }
+        filter
+    }
+
+    /// Builds a filter over the names carried by a single `GraphMessageFlow`
+    /// (its `name` field, or every entry of its `names` field).
+    pub fn from_flow(flow: &GraphMessageFlow) -> Self {
+        let single = flow.name.as_deref().into_iter();
+        let multi = flow.names.as_deref().into_iter().flatten().map(String::as_str);
+        Self::from_names(single.chain(multi))
+    }
+
+    fn bit_indices(&self, name: &str) -> impl Iterator<Item = usize> + '_ {
+        let nbits = self.bits.len() as u32;
+        (0..self.k).map(move |i| {
+            let seed = i.wrapping_mul(0xFBA4C795).wrapping_add(self.base_seed);
+            (murmur3_32(name.as_bytes(), seed) % nbits) as usize
+        })
+    }
+
+    fn insert(&mut self, name: &str) {
+        for idx in self.bit_indices(name).collect::<Vec<_>>() {
+            self.bits[idx] = true;
+        }
+    }
+
+    /// Returns `false` when `name` is definitely not among the names this
+    /// filter was built from; returns `true` when it is possibly present
+    /// (subject to the filter's false-positive rate).
+    pub fn might_contain(&self, name: &str) -> bool {
+        self.bit_indices(name).all(|idx| self.bits[idx])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inserted_names_are_always_reported_present() {
+        let names = ["cmd_a", "cmd_b", "cmd_c", "cmd_d", "cmd_e"];
+        let filter = NameBloomFilter::from_names(names.iter().copied());
+
+        for name in names {
+            assert!(filter.might_contain(name), "'{}' must never be a false negative", name);
+        }
+    }
+
+    #[test]
+    fn test_definitely_absent_name_can_be_reported_absent() {
+        let filter = NameBloomFilter::from_names(["cmd_a", "cmd_b"].into_iter());
+
+        // Not a correctness guarantee (bloom filters may false-positive), but
+        // with only two names in a filter sized for a ~1% false-positive
+        // rate, an unrelated name should be reported absent.
+        assert!(!filter.might_contain("totally_unrelated_name"));
+    }
+
+    #[test]
+    fn test_from_flow_covers_both_single_and_multi_name_fields() {
+        let single = GraphMessageFlow::new(
+            Some("only_name".to_string()),
+            None,
+            vec![],
+            vec![],
+        );
+        let filter = NameBloomFilter::from_flow(&single);
+        assert!(filter.might_contain("only_name"));
+
+        let multi = GraphMessageFlow::new(
+            None,
+            Some(vec!["name_a".to_string(), "name_b".to_string()]),
+            vec![],
+            vec![],
+        );
+        let filter = NameBloomFilter::from_flow(&multi);
+        assert!(filter.might_contain("name_a"));
+        assert!(filter.might_contain("name_b"));
+    }
+
+    #[test]
+    fn test_size_for_scales_bits_with_name_count() {
+        let (small_bits, _) = NameBloomFilter::size_for(1);
+        let (large_bits, _) = NameBloomFilter::size_for(1000);
+        assert!(large_bits > small_bits);
+    }
+}
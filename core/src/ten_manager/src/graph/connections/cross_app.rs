@@ -0,0 +1,246 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+use std::collections::HashMap;
+
+use anyhow::Result;
+use ten_rust::{
+    base_dir_pkg_info::PkgsInfoInApp,
+    graph::{connection::GraphLoc, msg_conversion::MsgAndResultConversion, Graph},
+    pkg_info::message::MsgType,
+};
+
+use super::add::graph_add_connection;
+
+/// A remote app's reachable addresses, analogous to a peer entry in Garage's
+/// `netapp` full-mesh layer.
+#[derive(Debug, Clone)]
+pub struct PeerEndpoint {
+    pub app_id: String,
+    pub addresses: Vec<String>,
+}
+
+/// A map of app-id to its reachable peer endpoint, consulted by
+/// `graph_add_cross_app_connection` to resolve where a cross-app
+/// destination actually lives.
+#[derive(Debug, Clone, Default)]
+pub struct PeerRegistry {
+    peers: HashMap<String, PeerEndpoint>,
+}
+
+impl PeerRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers (or replaces) the reachable addresses for `app_id`.
+    pub fn register_peer(&mut self, app_id: impl Into<String>, addresses: Vec<String>) {
+        let app_id = app_id.into();
+        self.peers.insert(app_id.clone(), PeerEndpoint {
+            app_id,
+            addresses,
+        });
+    }
+
+    /// Resolves `app_id` to its peer endpoint, failing fast when the app is
+    /// unknown or has no reachable address.
+    pub fn resolve(&self, app_id: &str) -> Result<&PeerEndpoint> {
+        let peer = self
+            .peers
+            .get(app_id)
+            .ok_or_else(|| anyhow::anyhow!("no peer registered for app '{}'", app_id))?;
+
+        if peer.addresses.is_empty() {
+            return Err(anyhow::anyhow!("peer for app '{}' has no reachable address", app_id));
+        }
+
+        Ok(peer)
+    }
+}
+
+/// The transport hop resolved for a message flow that crosses an app
+/// boundary: which app the destination resolved to, and the address(es) the
+/// message will actually be routed through. It is returned to the caller of
+/// `graph_add_cross_app_connection`; no hop node or connection is inserted
+/// into the graph for it.
+#[derive(Debug, Clone)]
+pub struct CrossAppRoute {
+    pub dest_app_id: String,
+    pub resolved_addresses: Vec<String>,
+}
+
+/// Adds a connection whose source and destination live in different apps,
+/// resolving the destination's peer endpoint from `peer_registry` before
+/// wiring it the same way `graph_add_connection` wires a local edge. Both
+/// endpoints are still validated to agree on the message schema via the
+/// existing `validate_connection_schema` machinery inside
+/// `graph_add_connection`; the peer resolution here only decides *whether*
+/// the destination app is reachable at all.
+///
+/// Fails fast, before touching the graph, when `dest` has no declared app,
+/// when `src` and `dest` declare the same app (use `graph_add_connection`
+/// for that), or when `dest`'s app is unknown to `peer_registry` or has no
+/// reachable address.
+#[allow(clippy::too_many_arguments)]
+pub async fn graph_add_cross_app_connection(
+    graph: &mut Graph,
+    graph_app_base_dir: &Option<String>,
+    pkgs_cache: &HashMap<String, PkgsInfoInApp>,
+    peer_registry: &PeerRegistry,
+    src: GraphLoc,
+    dest: GraphLoc,
+    msg_type: MsgType,
+    msg_names: Vec<String>,
+    msg_conversion: Option<MsgAndResultConversion>,
+) -> Result<CrossAppRoute> {
+    if src.get_app_uri() == dest.get_app_uri() {
+        return Err(anyhow::anyhow!(
+            "graph_add_cross_app_connection requires src and dest to declare different apps; \
+             use graph_add_connection for a same-app edge"
+        ));
+    }
+
+    let dest_app_id = dest
+        .get_app_uri()
+        .clone()
+        .ok_or_else(|| anyhow::anyhow!("a cross-app connection's destination must declare an app"))?;
+
+    let peer = peer_registry.resolve(&dest_app_id)?;
+    let route = CrossAppRoute {
+        dest_app_id,
+        resolved_addresses: peer.addresses.clone(),
+    };
+
+    graph_add_connection(graph, graph_app_base_dir, pkgs_cache, src, dest, msg_type, msg_names, msg_conversion)
+        .await?;
+
+    Ok(route)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use ten_rust::graph::node::GraphNode;
+
+    use super::*;
+
+    fn loc(app: Option<&str>, extension: &str) -> GraphLoc {
+        GraphLoc {
+            app: app.map(str::to_string),
+            extension: Some(extension.to_string()),
+            subgraph: None,
+            selector: None,
+        }
+    }
+
+    fn graph_with_extensions(names: &[&str]) -> Graph {
+        Graph {
+            nodes: names
+                .iter()
+                .map(|name| GraphNode::new_extension_node(name.to_string(), "addon".to_string(), None, None, None))
+                .collect(),
+            connections: Some(Vec::new()),
+            exposed_messages: None,
+            exposed_properties: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fails_fast_when_dest_app_is_unknown_to_registry() {
+        let mut graph = graph_with_extensions(&["ext_a", "ext_b"]);
+        let pkgs_cache = HashMap::new();
+        let registry = PeerRegistry::new();
+
+        let result = graph_add_cross_app_connection(
+            &mut graph,
+            &None,
+            &pkgs_cache,
+            &registry,
+            loc(None, "ext_a"),
+            loc(Some("app://remote"), "ext_b"),
+            MsgType::Cmd,
+            vec!["hello".to_string()],
+            None,
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert!(graph.connections.as_ref().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fails_fast_when_dest_app_has_no_reachable_address() {
+        let mut graph = graph_with_extensions(&["ext_a", "ext_b"]);
+        let pkgs_cache = HashMap::new();
+        let mut registry = PeerRegistry::new();
+        registry.register_peer("app://remote", vec![]);
+
+        let result = graph_add_cross_app_connection(
+            &mut graph,
+            &None,
+            &pkgs_cache,
+            &registry,
+            loc(None, "ext_a"),
+            loc(Some("app://remote"), "ext_b"),
+            MsgType::Cmd,
+            vec!["hello".to_string()],
+            None,
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fails_fast_when_src_and_dest_share_the_same_app() {
+        let mut graph = graph_with_extensions(&["ext_a", "ext_b"]);
+        let pkgs_cache = HashMap::new();
+        let mut registry = PeerRegistry::new();
+        registry.register_peer("app://same", vec!["127.0.0.1:8000".to_string()]);
+
+        let result = graph_add_cross_app_connection(
+            &mut graph,
+            &None,
+            &pkgs_cache,
+            &registry,
+            loc(Some("app://same"), "ext_a"),
+            loc(Some("app://same"), "ext_b"),
+            MsgType::Cmd,
+            vec!["hello".to_string()],
+            None,
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_resolves_peer_and_wires_connection_when_app_is_known() {
+        let mut graph = graph_with_extensions(&["ext_a", "ext_b"]);
+        let pkgs_cache = HashMap::new();
+        let mut registry = PeerRegistry::new();
+        registry.register_peer("app://remote", vec!["127.0.0.1:8000".to_string()]);
+
+        let route = graph_add_cross_app_connection(
+            &mut graph,
+            &None,
+            &pkgs_cache,
+            &registry,
+            loc(None, "ext_a"),
+            loc(Some("app://remote"), "ext_b"),
+            MsgType::Cmd,
+            vec!["hello".to_string()],
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(route.dest_app_id, "app://remote");
+        assert_eq!(route.resolved_addresses, vec!["127.0.0.1:8000".to_string()]);
+        assert_eq!(graph.connections.as_ref().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,191 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+use std::collections::{HashMap, HashSet};
+
+use ten_rust::{
+    graph::{connection::GraphLoc, Graph},
+    pkg_info::message::MsgType,
+};
+
+/// A persistent index over a `Graph`'s connections, in the spirit of Deno's
+/// `GraphData`/`ModuleEntry` map: it lets `graph_add_connection_indexed`
+/// answer "does this connection already exist" and "which `Vec` slot holds
+/// the connection for this source" in O(1) instead of re-scanning every
+/// connection, flow, and destination on every call. Bulk-wiring a graph with
+/// thousands of flows therefore stays roughly linear instead of quadratic.
+///
+/// The index must be kept in sync with the `Graph` it describes: build it
+/// once with [`ConnectionGraphIndex::from_graph`], then only mutate the
+/// `Graph` through [`super::add::graph_add_connection_indexed`], which
+/// updates both the `Vec<GraphConnection>` and this index together.
+#[derive(Debug, Default)]
+pub struct ConnectionGraphIndex {
+    /// Maps a source `GraphLoc` to its slot in `graph.connections`.
+    connection_slots: HashMap<GraphLoc, usize>,
+
+    /// Maps a source `GraphLoc` to the set of `(msg_type, msg_name)` message
+    /// flows it carries, each with its set of destination locs.
+    edges: HashMap<GraphLoc, HashMap<(MsgType, String), HashSet<GraphLoc>>>,
+}
+
+impl ConnectionGraphIndex {
+    /// Creates an empty index, matching an empty (or `None`) connections
+    /// list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds an index from an existing `Graph`'s `connections`, so the
+    /// index can be constructed lazily right before a batch of edits rather
+    /// than being threaded through every graph-reading code path.
+    pub fn from_graph(graph: &Graph) -> Self {
+        let mut index = Self::new();
+
+        let Some(connections) = &graph.connections else {
+            return index;
+        };
+
+        for (slot, conn) in connections.iter().enumerate() {
+            index.connection_slots.insert(conn.loc.clone(), slot);
+
+            let flow_lists = [
+                (MsgType::Cmd, &conn.cmd),
+                (MsgType::Data, &conn.data),
+                (MsgType::AudioFrame, &conn.audio_frame),
+                (MsgType::VideoFrame, &conn.video_frame),
+            ];
+
+            for (msg_type, flows) in flow_lists {
+                let Some(flows) = flows else {
+                    continue;
+                };
+
+                for flow in flows {
+                    let names: Vec<String> = if let Some(name) = &flow.name {
+                        vec![name.clone()]
+                    } else {
+                        flow.names.clone().unwrap_or_default()
+                    };
+
+                    for name in names {
+                        let dests =
+                            index.edges.entry(conn.loc.clone()).or_default().entry((msg_type, name)).or_default();
+                        for dest in &flow.dest {
+                            dests.insert(dest.loc.clone());
+                        }
+                    }
+                }
+            }
+        }
+
+        index
+    }
+
+    /// Returns the slot of `src` in `graph.connections`, if one already
+    /// exists.
+    pub fn connection_slot(&self, src: &GraphLoc) -> Option<usize> {
+        self.connection_slots.get(src).copied()
+    }
+
+    /// Records that `src` now occupies `slot` in `graph.connections` (called
+    /// right after a new `GraphConnection` is pushed).
+    pub fn record_connection_slot(&mut self, src: GraphLoc, slot: usize) {
+        self.connection_slots.insert(src, slot);
+    }
+
+    /// Returns whether a connection from `src` to `dest` already exists for
+    /// `msg_type`/`msg_name`.
+    pub fn contains_edge(&self, src: &GraphLoc, msg_type: MsgType, msg_name: &str, dest: &GraphLoc) -> bool {
+        self.edges
+            .get(src)
+            .and_then(|flows| flows.get(&(msg_type, msg_name.to_string())))
+            .is_some_and(|dests| dests.contains(dest))
+    }
+
+    /// Records a newly-added destination for `src`/`msg_type`/`msg_name`.
+    pub fn insert_edge(&mut self, src: GraphLoc, msg_type: MsgType, msg_name: String, dest: GraphLoc) {
+        self.edges.entry(src).or_default().entry((msg_type, msg_name)).or_default().insert(dest);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ten_rust::graph::connection::{GraphConnection, GraphDestination, GraphMessageFlow};
+
+    use super::*;
+
+    fn loc(name: &str) -> GraphLoc {
+        GraphLoc {
+            app: None,
+            extension: Some(name.to_string()),
+            subgraph: None,
+            selector: None,
+        }
+    }
+
+    fn connection_from(src_ext: &str, msg_name: &str, dest_ext: &str) -> GraphConnection {
+        GraphConnection {
+            loc: loc(src_ext),
+            cmd: Some(vec![GraphMessageFlow::new(
+                Some(msg_name.to_string()),
+                None,
+                vec![GraphDestination {
+                    loc: loc(dest_ext),
+                    msg_conversion: None,
+                }],
+                vec![],
+            )]),
+            data: None,
+            audio_frame: None,
+            video_frame: None,
+        }
+    }
+
+    #[test]
+    fn test_from_graph_indexes_existing_connections() {
+        let graph = Graph {
+            nodes: vec![],
+            connections: Some(vec![connection_from("ext_a", "hello", "ext_b")]),
+            exposed_messages: None,
+            exposed_properties: None,
+        };
+
+        let index = ConnectionGraphIndex::from_graph(&graph);
+
+        assert_eq!(index.connection_slot(&loc("ext_a")), Some(0));
+        assert!(index.contains_edge(&loc("ext_a"), MsgType::Cmd, "hello", &loc("ext_b")));
+        assert!(!index.contains_edge(&loc("ext_a"), MsgType::Cmd, "hello", &loc("ext_c")));
+        assert!(!index.contains_edge(&loc("ext_a"), MsgType::Data, "hello", &loc("ext_b")));
+    }
+
+    #[test]
+    fn test_empty_graph_has_no_slots_or_edges() {
+        let graph = Graph {
+            nodes: vec![],
+            connections: None,
+            exposed_messages: None,
+            exposed_properties: None,
+        };
+
+        let index = ConnectionGraphIndex::from_graph(&graph);
+
+        assert_eq!(index.connection_slot(&loc("ext_a")), None);
+        assert!(!index.contains_edge(&loc("ext_a"), MsgType::Cmd, "hello", &loc("ext_b")));
+    }
+
+    #[test]
+    fn test_record_and_insert_edge_are_reflected_in_lookups() {
+        let mut index = ConnectionGraphIndex::new();
+
+        index.record_connection_slot(loc("ext_a"), 0);
+        assert_eq!(index.connection_slot(&loc("ext_a")), Some(0));
+
+        assert!(!index.contains_edge(&loc("ext_a"), MsgType::Cmd, "hello", &loc("ext_b")));
+        index.insert_edge(loc("ext_a"), MsgType::Cmd, "hello".to_string(), loc("ext_b"));
+        assert!(index.contains_edge(&loc("ext_a"), MsgType::Cmd, "hello", &loc("ext_b")));
+    }
+}
@@ -0,0 +1,346 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use anyhow::Result;
+use ten_rust::{
+    base_dir_pkg_info::PkgsInfoInApp,
+    graph::{connection::GraphLoc, node::GraphNodeType, Graph},
+    pkg_info::message::MsgType,
+};
+
+use super::validate::{validate_connection_schema, MsgConversionValidateInfo};
+
+/// Returns the set of destinations directly reachable from `src` for
+/// `msg_type`, restricted to edges whose downstream handler actually
+/// validates via `validate_connection_schema` -- the same machinery
+/// `graph_add_connection` uses -- so an edge that is declared in the graph
+/// but whose schema wouldn't actually accept the message is not treated as
+/// real deliverability.
+async fn outgoing_edges(
+    graph: &Graph,
+    pkgs_cache: &HashMap<String, PkgsInfoInApp>,
+    graph_app_base_dir: &Option<String>,
+    src: &GraphLoc,
+    msg_type: MsgType,
+) -> Vec<GraphLoc> {
+    let mut edges = Vec::new();
+
+    let Some(connections) = &graph.connections else {
+        return edges;
+    };
+
+    for conn in connections {
+        if !conn.loc.matches(src) {
+            continue;
+        }
+
+        let flows = match msg_type {
+            MsgType::Cmd => conn.cmd.as_ref(),
+            MsgType::Data => conn.data.as_ref(),
+            MsgType::AudioFrame => conn.audio_frame.as_ref(),
+            MsgType::VideoFrame => conn.video_frame.as_ref(),
+        };
+        let Some(flows) = flows else {
+            continue;
+        };
+
+        for flow in flows {
+            let names: Vec<String> = flow.name.clone().into_iter().chain(flow.names.clone().unwrap_or_default()).collect();
+
+            for dest_item in &flow.dest {
+                for name in &names {
+                    let info = MsgConversionValidateInfo {
+                        src: &conn.loc,
+                        dest: &dest_item.loc,
+                        msg_type: &msg_type,
+                        msg_names: &vec![name.clone()],
+                        msg_conversion: &dest_item.msg_conversion,
+                    };
+
+                    if validate_connection_schema(pkgs_cache, graph, graph_app_base_dir, &info).await.is_ok() {
+                        edges.push(dest_item.loc.clone());
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    edges
+}
+
+/// Finds a path from `src` to `dest` over `msg_type`-typed message flows
+/// using BFS (shortest in number of hops), treating each validated
+/// `GraphMessageFlow` destination as a directed edge. Returns `None` when no
+/// such path exists, analogous to a `NetworkGraph` routing query finding no
+/// path over channel edges.
+pub async fn graph_find_path(
+    graph: &Graph,
+    pkgs_cache: &HashMap<String, PkgsInfoInApp>,
+    graph_app_base_dir: &Option<String>,
+    src: &GraphLoc,
+    dest: &GraphLoc,
+    msg_type: MsgType,
+) -> Result<Option<Vec<GraphLoc>>> {
+    if src.matches(dest) {
+        return Ok(Some(vec![src.clone()]));
+    }
+
+    let mut visited: HashSet<GraphLoc> = HashSet::new();
+    visited.insert(src.clone());
+
+    let mut queue: VecDeque<GraphLoc> = VecDeque::new();
+    queue.push_back(src.clone());
+
+    let mut predecessor: HashMap<GraphLoc, GraphLoc> = HashMap::new();
+
+    while let Some(current) = queue.pop_front() {
+        let neighbors = outgoing_edges(graph, pkgs_cache, graph_app_base_dir, &current, msg_type).await;
+
+        for neighbor in neighbors {
+            if !visited.insert(neighbor.clone()) {
+                continue;
+            }
+            predecessor.insert(neighbor.clone(), current.clone());
+
+            if neighbor.matches(dest) {
+                let mut path = vec![neighbor.clone()];
+                let mut cursor = neighbor;
+                while let Some(prev) = predecessor.get(&cursor) {
+                    path.push(prev.clone());
+                    cursor = prev.clone();
+                }
+                path.reverse();
+                return Ok(Some(path));
+            }
+
+            queue.push_back(neighbor);
+        }
+    }
+
+    Ok(None)
+}
+
+/// Returns every node in the graph that has no inbound, schema-validated
+/// path from any declared source, for any message type. Lets a user confirm
+/// an audio/video pipeline is fully wired (no dead-end extensions) before
+/// runtime.
+pub async fn graph_unreachable_nodes(
+    graph: &Graph,
+    pkgs_cache: &HashMap<String, PkgsInfoInApp>,
+    graph_app_base_dir: &Option<String>,
+) -> Result<Vec<GraphLoc>> {
+    const ALL_MSG_TYPES: [MsgType; 4] =
+        [MsgType::Cmd, MsgType::Data, MsgType::AudioFrame, MsgType::VideoFrame];
+
+    // Every extension declared in the graph is a candidate, whether or not
+    // it participates in any connection at all -- an extension with zero
+    // connections is the primary "dead-end extension" case this function
+    // exists to catch.
+    let mut all_locs: HashSet<GraphLoc> = HashSet::new();
+    for node in &graph.nodes {
+        if node.get_type() != GraphNodeType::Extension {
+            continue;
+        }
+        if let Ok(loc) =
+            GraphLoc::with_app_and_type_and_name(None, GraphNodeType::Extension, node.get_name().to_string())
+        {
+            all_locs.insert(loc);
+        }
+    }
+
+    let mut conn_locs: HashSet<GraphLoc> = HashSet::new();
+    let mut dest_locs: HashSet<GraphLoc> = HashSet::new();
+    if let Some(connections) = &graph.connections {
+        for conn in connections {
+            all_locs.insert(conn.loc.clone());
+            conn_locs.insert(conn.loc.clone());
+            for flows in [&conn.cmd, &conn.data, &conn.audio_frame, &conn.video_frame] {
+                let Some(flows) = flows else {
+                    continue;
+                };
+                for flow in flows {
+                    for dest_item in &flow.dest {
+                        all_locs.insert(dest_item.loc.clone());
+                        dest_locs.insert(dest_item.loc.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    // Declared sources: locs that actually appear as a connection's source
+    // and are never themselves a destination. A node that never appears in
+    // any connection at all (an isolated extension) is deliberately left
+    // out of this seed set -- it must fall out of the traversal unreached
+    // below, not be waved through as a "source".
+    let sources: Vec<GraphLoc> = conn_locs.iter().filter(|loc| !dest_locs.contains(*loc)).cloned().collect();
+
+    let mut reachable: HashSet<GraphLoc> = sources.iter().cloned().collect();
+    let mut worklist: VecDeque<GraphLoc> = sources.into_iter().collect();
+
+    while let Some(current) = worklist.pop_front() {
+        for msg_type in ALL_MSG_TYPES {
+            for neighbor in outgoing_edges(graph, pkgs_cache, graph_app_base_dir, &current, msg_type).await {
+                if reachable.insert(neighbor.clone()) {
+                    worklist.push_back(neighbor);
+                }
+            }
+        }
+    }
+
+    let mut unreachable: Vec<GraphLoc> = all_locs.into_iter().filter(|loc| !reachable.contains(loc)).collect();
+    unreachable.sort_by(|a, b| format!("{:?}", a).cmp(&format!("{:?}", b)));
+
+    Ok(unreachable)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use ten_rust::graph::{
+        connection::{GraphConnection, GraphDestination, GraphMessageFlow},
+        node::GraphNode,
+    };
+
+    use super::*;
+
+    fn loc(name: &str) -> GraphLoc {
+        GraphLoc {
+            app: None,
+            extension: Some(name.to_string()),
+            subgraph: None,
+            selector: None,
+        }
+    }
+
+    fn connection_from(src_ext: &str, msg_name: &str, dest_ext: &str) -> GraphConnection {
+        GraphConnection {
+            loc: loc(src_ext),
+            cmd: Some(vec![GraphMessageFlow::new(
+                Some(msg_name.to_string()),
+                None,
+                vec![GraphDestination {
+                    loc: loc(dest_ext),
+                    msg_conversion: None,
+                }],
+                vec![],
+            )]),
+            data: None,
+            audio_frame: None,
+            video_frame: None,
+        }
+    }
+
+    fn chained_graph(extensions: &[&str]) -> Graph {
+        Graph {
+            nodes: extensions
+                .iter()
+                .map(|name| GraphNode::new_extension_node(name.to_string(), "addon".to_string(), None, None, None))
+                .collect(),
+            connections: Some(
+                extensions
+                    .windows(2)
+                    .map(|pair| connection_from(pair[0], "hello", pair[1]))
+                    .collect(),
+            ),
+            exposed_messages: None,
+            exposed_properties: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_find_path_follows_chained_flows() {
+        let graph = chained_graph(&["ext_a", "ext_b", "ext_c"]);
+        let pkgs_cache = HashMap::new();
+
+        let path =
+            graph_find_path(&graph, &pkgs_cache, &None, &loc("ext_a"), &loc("ext_c"), MsgType::Cmd)
+                .await
+                .unwrap();
+
+        assert_eq!(path, Some(vec![loc("ext_a"), loc("ext_b"), loc("ext_c")]));
+    }
+
+    #[tokio::test]
+    async fn test_find_path_returns_none_when_unreachable() {
+        let mut graph = chained_graph(&["ext_a", "ext_b"]);
+        graph.nodes.push(GraphNode::new_extension_node(
+            "ext_isolated".to_string(),
+            "addon".to_string(),
+            None,
+            None,
+            None,
+        ));
+        let pkgs_cache = HashMap::new();
+
+        let path = graph_find_path(
+            &graph,
+            &pkgs_cache,
+            &None,
+            &loc("ext_a"),
+            &loc("ext_isolated"),
+            MsgType::Cmd,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(path, None);
+    }
+
+    #[tokio::test]
+    async fn test_find_path_same_source_and_dest_is_trivially_reachable() {
+        let graph = chained_graph(&["ext_a", "ext_b"]);
+        let pkgs_cache = HashMap::new();
+
+        let path =
+            graph_find_path(&graph, &pkgs_cache, &None, &loc("ext_a"), &loc("ext_a"), MsgType::Cmd)
+                .await
+                .unwrap();
+
+        assert_eq!(path, Some(vec![loc("ext_a")]));
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_nodes_flags_dead_end_and_isolated_extensions() {
+        let mut graph = chained_graph(&["ext_a", "ext_b"]);
+        graph.nodes.push(GraphNode::new_extension_node(
+            "ext_isolated".to_string(),
+            "addon".to_string(),
+            None,
+            None,
+            None,
+        ));
+        let pkgs_cache = HashMap::new();
+
+        let unreachable = graph_unreachable_nodes(&graph, &pkgs_cache, &None).await.unwrap();
+
+        // ext_isolated never appears in any connection at all, which makes
+        // it the primary "dead-end extension" case this function exists to
+        // catch; ext_a (a declared source) and ext_b (reachable from it) are
+        // both wired and must not be flagged.
+        assert!(unreachable.contains(&loc("ext_isolated")));
+        assert!(!unreachable.contains(&loc("ext_a")));
+        assert!(!unreachable.contains(&loc("ext_b")));
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_nodes_empty_when_no_connections() {
+        let graph = Graph {
+            nodes: vec![],
+            connections: None,
+            exposed_messages: None,
+            exposed_properties: None,
+        };
+        let pkgs_cache = HashMap::new();
+
+        let unreachable = graph_unreachable_nodes(&graph, &pkgs_cache, &None).await.unwrap();
+        assert!(unreachable.is_empty());
+    }
+}
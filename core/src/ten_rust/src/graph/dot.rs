@@ -0,0 +1,215 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Write as _,
+};
+
+use crate::graph::{connection::GraphLoc, graph_info::GraphContent, Graph};
+
+/// Escapes double quotes and backslashes so a string can be safely embedded
+/// inside a Graphviz quoted identifier or label.
+fn escape_dot_string(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Builds a stable DOT node identifier for a graph location, prefixing it
+/// with the app URI (when present) so identifiers stay unique across apps in
+/// a multi-app graph.
+fn dot_node_id(loc: &GraphLoc) -> String {
+    let name = loc.get_node_name().map(|n| n.as_str()).unwrap_or("unknown");
+    match loc.get_app_uri() {
+        Some(app) => format!("{}::{}", app, name),
+        None => name.to_string(),
+    }
+}
+
+/// Builds the DOT edge label for a message flow: the message kind
+/// (`cmd`/`data`/`audio_frame`/`video_frame`) plus the flow's `name` or
+/// `names`.
+fn dot_edge_label(kind: &str, name: Option<&str>, names: Option<&[String]>) -> String {
+    let msg_name = name.map(str::to_string).or_else(|| names.map(|n| n.join(", "))).unwrap_or_default();
+    format!("{}: {}", kind, msg_name)
+}
+
+/// The node name `inject_graph_proxy_from_exposed_messages` uses for its
+/// synthetic cross-graph forwarding node.
+const GRAPH_PROXY_NODE_NAME: &str = "ten:graph_proxy";
+
+/// Picks a distinguishing `shape` attribute for a DOT node: `ten:graph_proxy`
+/// (the synthetic node `inject_graph_proxy_from_exposed_messages` injects)
+/// gets a `box3d`, so users can immediately spot where a graph's
+/// exposed-message entry/exit points actually land.
+fn dot_node_shape(name: &str) -> Option<&'static str> {
+    if name == GRAPH_PROXY_NODE_NAME {
+        Some("box3d")
+    } else {
+        None
+    }
+}
+
+/// Picks the `color`/`style` attributes Graphviz uses to distinguish each
+/// message class's edges at a glance.
+fn dot_edge_style(kind: &str) -> (&'static str, &'static str) {
+    match kind {
+        "cmd" => ("black", "solid"),
+        "data" => ("blue", "solid"),
+        "audio_frame" => ("darkgreen", "dashed"),
+        "video_frame" => ("darkorange", "dashed"),
+        _ => ("gray", "solid"),
+    }
+}
+
+/// Builds the `[label=...]`-style attribute list for a DOT node, adding a
+/// `shape` attribute when `name` designates a special node (currently just
+/// the injected `ten:graph_proxy` node).
+fn dot_node_attrs(name: &str, label: &str) -> String {
+    match dot_node_shape(name) {
+        Some(shape) => format!("[label=\"{}\", shape={}]", escape_dot_string(label), shape),
+        None => format!("[label=\"{}\"]", escape_dot_string(label)),
+    }
+}
+
+impl Graph {
+    /// Serializes the graph's topology into Graphviz DOT so users can
+    /// visualize extension/subgraph/selector wiring. Emits one node per
+    /// `GraphNode` and one directed edge per `GraphDestination` in each
+    /// `GraphMessageFlow`, labeling edges with the message kind
+    /// (`cmd`/`data`/`audio_frame`/`video_frame`) plus the flow's name(s),
+    /// colored and styled distinctly per message class. Locations whose
+    /// `app` is set are grouped into cluster subgraphs so multi-app graphs
+    /// group visually. The synthetic `ten:graph_proxy` node that
+    /// `inject_graph_proxy_from_exposed_messages` injects for a graph's
+    /// exposed-message entry/exit points is rendered with a `box3d` shape so
+    /// it stands out from ordinary extension nodes.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        let _ = writeln!(dot, "digraph G {{");
+
+        // Nodes grouped by app URI (None means the local/default app), so
+        // app-qualified locations end up in their own cluster subgraph.
+        let mut clusters: BTreeMap<Option<String>, Vec<String>> = BTreeMap::new();
+
+        // Every node id already declared via `self.nodes`, so a connection
+        // endpoint referencing one of them isn't re-declared below with the
+        // inferior plain-name label -- Graphviz keeps only the last `label=`
+        // it sees for a given node id, so a second declaration would
+        // silently clobber the "name (Type)" label the first one set.
+        let mut declared_ids: HashSet<String> = HashSet::new();
+
+        for node in &self.nodes {
+            let name = node.get_name().to_string();
+            let id = match node.get_app_uri() {
+                Some(app) => format!("{}::{}", app, name),
+                None => name.clone(),
+            };
+            let label = format!("{} ({:?})", node.get_name(), node.get_type());
+            clusters.entry(node.get_app_uri().clone()).or_default().push(format!(
+                "  \"{}\" {};",
+                escape_dot_string(&id),
+                dot_node_attrs(&name, &label)
+            ));
+            declared_ids.insert(id);
+        }
+
+        let mut edges = Vec::new();
+        if let Some(connections) = &self.connections {
+            for conn in connections {
+                let src_id = dot_node_id(&conn.loc);
+                if let Some(app) = conn.loc.get_app_uri() {
+                    if declared_ids.insert(src_id.clone()) {
+                        let name = conn.loc.get_node_name().map(|n| n.as_str()).unwrap_or("unknown");
+                        clusters.entry(Some(app.clone())).or_default().push(format!(
+                            "  \"{}\" {};",
+                            escape_dot_string(&src_id),
+                            dot_node_attrs(name, name)
+                        ));
+                    }
+                }
+
+                for (kind, flows) in [
+                    ("cmd", &conn.cmd),
+                    ("data", &conn.data),
+                    ("audio_frame", &conn.audio_frame),
+                    ("video_frame", &conn.video_frame),
+                ] {
+                    let Some(flows) = flows else {
+                        continue;
+                    };
+                    for flow in flows {
+                        let label = dot_edge_label(kind, flow.name.as_deref(), flow.names.as_deref());
+                        let (color, style) = dot_edge_style(kind);
+                        for dest in &flow.dest {
+                            let dest_id = dot_node_id(&dest.loc);
+                            if let Some(app) = dest.loc.get_app_uri() {
+                                if declared_ids.insert(dest_id.clone()) {
+                                    let name =
+                                        dest.loc.get_node_name().map(|n| n.as_str()).unwrap_or("unknown");
+                                    clusters.entry(Some(app.clone())).or_default().push(format!(
+                                        "  \"{}\" {};",
+                                        escape_dot_string(&dest_id),
+                                        dot_node_attrs(name, name)
+                                    ));
+                                }
+                            }
+                            edges.push(format!(
+                                "  \"{}\" -> \"{}\" [label=\"{}\", color={}, style={}];",
+                                escape_dot_string(&src_id),
+                                escape_dot_string(&dest_id),
+                                escape_dot_string(&label),
+                                color,
+                                style
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        for (app, nodes) in &clusters {
+            match app {
+                None => {
+                    for line in nodes {
+                        let _ = writeln!(dot, "{}", line);
+                    }
+                }
+                Some(app_uri) => {
+                    let _ = writeln!(dot, "  subgraph \"cluster_{}\" {{", escape_dot_string(app_uri));
+                    let _ = writeln!(dot, "    label=\"{}\";", escape_dot_string(app_uri));
+                    for line in nodes {
+                        let _ = writeln!(dot, "  {}", line);
+                    }
+                    let _ = writeln!(dot, "  }}");
+                }
+            }
+        }
+
+        for edge in &edges {
+            let _ = writeln!(dot, "{}", edge);
+        }
+
+        let _ = writeln!(dot, "}}");
+
+        dot
+    }
+}
+
+impl GraphContent {
+    /// Serializes the flattened topology into Graphviz DOT. Runs
+    /// `validate_and_complete_and_flatten` first so selector/subgraph
+    /// expansion and cross-app wiring are reflected in the exported graph.
+    pub async fn to_dot(&mut self, app_base_dir: Option<&str>) -> anyhow::Result<String> {
+        self.validate_and_complete_and_flatten(app_base_dir).await?;
+
+        let flattened_graph = self
+            .flattened_graph
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("graph was not flattened"))?;
+
+        Ok(flattened_graph.to_dot())
+    }
+}
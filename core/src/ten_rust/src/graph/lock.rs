@@ -0,0 +1,212 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+use std::{
+    collections::{BTreeMap, HashSet},
+    hash::{Hash, Hasher},
+};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::graph::{
+    connection::GraphConnection, graph_info::GraphContent, node::GraphNodeType,
+    subgraph::collect_subgraph_member_extension_names,
+};
+
+/// The resolved state of a single `selector`/`subgraph` node as of the last
+/// `GraphContent::write_lock()` call.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct GraphLockEntry {
+    /// The ordered list of extension names this selector/subgraph expanded
+    /// to.
+    pub members: Vec<String>,
+
+    /// A content hash of the resulting merged connection set, so a change in
+    /// wiring (not just membership) is also detected.
+    pub content_hash: String,
+}
+
+/// A deterministic snapshot of every `selector`/`subgraph` expansion in a
+/// graph, keyed by node name. Mirrors how module-graph tooling pins resolved
+/// dependencies, so a later edit that silently changes what a selector
+/// resolves to can be caught in review/CI.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct GraphLockfile {
+    pub entries: BTreeMap<String, GraphLockEntry>,
+}
+
+/// Describes how a re-expansion of the graph differs from a stored lockfile.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GraphLockDiff {
+    /// Selectors/subgraphs present now but missing from the lockfile.
+    pub added: Vec<String>,
+    /// Selectors/subgraphs present in the lockfile but missing now.
+    pub removed: Vec<String>,
+    /// Selectors/subgraphs present in both, but whose members or content
+    /// hash changed (including pure reordering, since `members` is ordered).
+    pub changed: Vec<String>,
+}
+
+impl GraphLockDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Builds a deterministic digest of every connection touching one of
+/// `members` (as either the connection's own source or a destination of one
+/// of its flows), so a wiring change — a different destination, message
+/// name, or msg_conversion — is caught even when membership itself is
+/// unchanged.
+fn connection_set_digest(connections: &[GraphConnection], members: &HashSet<&str>) -> String {
+    let touches_members = |conn: &GraphConnection| -> bool {
+        if conn
+            .loc
+            .get_node_name()
+            .map(|n| members.contains(n.as_str()))
+            .unwrap_or(false)
+        {
+            return true;
+        }
+
+        [&conn.cmd, &conn.data, &conn.audio_frame, &conn.video_frame]
+            .into_iter()
+            .flatten()
+            .any(|flows| {
+                flows.iter().any(|flow| {
+                    flow.dest.iter().any(|dest| {
+                        dest.loc
+                            .get_node_name()
+                            .map(|n| members.contains(n.as_str()))
+                            .unwrap_or(false)
+                    })
+                })
+            })
+    };
+
+    let mut serialized: Vec<String> = connections
+        .iter()
+        .filter(|conn| touches_members(conn))
+        .map(|conn| serde_json::to_string(conn).unwrap_or_default())
+        .collect();
+    serialized.sort();
+    serialized.join("\n")
+}
+
+fn hash_entry(name: &str, members: &[String], connections_digest: &str) -> String {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    name.hash(&mut hasher);
+    for member in members {
+        member.hash(&mut hasher);
+    }
+    connections_digest.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+impl GraphLockfile {
+    /// Compares this (expected/stored) lockfile against a freshly re-expanded
+    /// one, reporting which selectors/subgraphs were added, removed, or now
+    /// resolve differently.
+    pub fn diff(&self, current: &GraphLockfile) -> GraphLockDiff {
+        let mut diff = GraphLockDiff::default();
+
+        for name in current.entries.keys() {
+            if !self.entries.contains_key(name) {
+                diff.added.push(name.clone());
+            }
+        }
+
+        for (name, locked_entry) in &self.entries {
+            match current.entries.get(name) {
+                None => diff.removed.push(name.clone()),
+                Some(current_entry) => {
+                    if current_entry != locked_entry {
+                        diff.changed.push(name.clone());
+                    }
+                }
+            }
+        }
+
+        diff.added.sort();
+        diff.removed.sort();
+        diff.changed.sort();
+        diff
+    }
+}
+
+impl GraphContent {
+    /// Records, for each `selector`/`subgraph` node in the pre-flatten
+    /// graph, the exact ordered list of extension names it expanded to plus
+    /// a content hash of the resulting merged connection set.
+    pub async fn write_lock(&mut self, app_base_dir: Option<&str>) -> Result<GraphLockfile> {
+        self.validate_and_complete_and_flatten(app_base_dir).await?;
+
+        let flattened_graph = self
+            .flattened_graph
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("graph was not flattened"))?;
+
+        let app_base_dir = app_base_dir.map(str::to_string);
+
+        let mut entries = BTreeMap::new();
+        for node in &self.nodes {
+            let node_type = node.get_type();
+            if node_type != GraphNodeType::Selector && node_type != GraphNodeType::Subgraph {
+                continue;
+            }
+
+            let name = node.get_name().to_string();
+            let members = match node_type {
+                GraphNodeType::Selector => flattened_graph
+                    .get_nodes_by_selector_node_name(&name)
+                    .map(|nodes| nodes.iter().map(|n| n.get_name().to_string()).collect())
+                    .unwrap_or_default(),
+                _ => {
+                    collect_subgraph_member_extension_names(
+                        &self.nodes,
+                        &app_base_dir,
+                        &name,
+                        None,
+                        None,
+                        &mut None,
+                    )
+                    .await?
+                }
+            };
+
+            let members_set: HashSet<&str> = members.iter().map(String::as_str).collect();
+            let connections_digest = flattened_graph
+                .connections
+                .as_ref()
+                .map(|connections| connection_set_digest(connections, &members_set))
+                .unwrap_or_default();
+
+            let content_hash = hash_entry(&name, &members, &connections_digest);
+            entries.insert(
+                name,
+                GraphLockEntry {
+                    members,
+                    content_hash,
+                },
+            );
+        }
+
+        Ok(GraphLockfile { entries })
+    }
+
+    /// Re-expands the graph and compares the result against a previously
+    /// stored lockfile, returning a diff of which selectors/subgraphs now
+    /// resolve differently.
+    pub async fn verify_against_lock(
+        &mut self,
+        app_base_dir: Option<&str>,
+        stored: &GraphLockfile,
+    ) -> Result<GraphLockDiff> {
+        let current = self.write_lock(app_base_dir).await?;
+        Ok(stored.diff(&current))
+    }
+}
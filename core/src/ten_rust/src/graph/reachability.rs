@@ -0,0 +1,265 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+use std::collections::{HashSet, VecDeque};
+
+use serde::{Deserialize, Serialize};
+
+use crate::graph::{connection::GraphLoc, node::GraphNodeType, Graph, GraphExposedMessageType};
+
+/// A node key used to identify an extension in the reachability graph. Keyed
+/// by `(app, extension)` so that same-named extensions in different apps are
+/// tracked independently.
+type NodeKey = (Option<String>, String);
+
+fn node_key(loc: &GraphLoc) -> Option<NodeKey> {
+    Some((loc.get_app_uri().clone(), loc.extension.clone()?))
+}
+
+/// Reports the result of running `Graph::analyze_reachability`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ReachabilityReport {
+    /// Extension nodes declared in the graph that can never receive any
+    /// message, even counting the graph's own `exposed_messages` `*In`
+    /// entries as external entry points.
+    pub unreachable_nodes: Vec<String>,
+
+    /// Indexes (into `Graph::connections`) of message flows whose
+    /// destinations are never reachable from a source.
+    pub orphan_flows: Vec<usize>,
+
+    /// Extension nodes whose output can never escape the graph: running the
+    /// forward traversal in reverse from the graph's `exposed_messages`
+    /// `*Out` entries never reaches them, so whatever they emit is dropped
+    /// on the floor instead of being forwarded out.
+    pub dead_output_nodes: Vec<String>,
+}
+
+impl Graph {
+    /// Performs a worklist dataflow over the directed graph induced by
+    /// `GraphConnection -> GraphMessageFlow.dest -> GraphDestination.loc` to
+    /// find extensions that can never receive any message, and message flows
+    /// whose destinations are never reachable from a source.
+    ///
+    /// Entry nodes are extensions that appear as a connection's own `loc` or
+    /// as a `GraphSource` in some flow's `source` but never as a destination
+    /// anywhere in the graph, or that are named by an `exposed_messages`
+    /// entry of type `*In` (an external caller can invoke them directly). An
+    /// extension that appears in no connection at all is not an entry node. The live set is then grown by repeatedly following
+    /// out-edges until it reaches a fixpoint, which also guarantees
+    /// termination in the presence of cycles. A flow whose source `loc` is
+    /// an unresolved `subgraph`/`selector` (not yet broken down into
+    /// concrete extensions) is handled conservatively: its destinations are
+    /// seeded as live rather than risking a false "unreachable" report,
+    /// since flattening normally resolves these before this analysis runs.
+    ///
+    /// Also runs the traversal in reverse from the graph's `exposed_messages`
+    /// `*Out` entries to find extensions whose output can never escape the
+    /// graph (nothing forwards it to an exposed out point); these are
+    /// reported separately in `dead_output_nodes` and only computed when the
+    /// graph declares at least one `*Out` exposed message.
+    pub fn analyze_reachability(&self) -> ReachabilityReport {
+        let mut adjacency: std::collections::HashMap<NodeKey, Vec<NodeKey>> =
+            std::collections::HashMap::new();
+        let mut reverse_adjacency: std::collections::HashMap<NodeKey, Vec<NodeKey>> =
+            std::collections::HashMap::new();
+        let mut dest_keys: HashSet<NodeKey> = HashSet::new();
+        let mut source_keys: HashSet<NodeKey> = HashSet::new();
+        // Destinations of a flow whose own source loc could not be resolved
+        // to a concrete extension (an unflattened subgraph/selector);
+        // treated as reachable unconditionally.
+        let mut unconditional_roots: HashSet<NodeKey> = HashSet::new();
+        // The app an extension name was last observed under, used to key
+        // isolated nodes that never appear in a connection.
+        let mut known_apps: std::collections::HashMap<String, Option<String>> =
+            std::collections::HashMap::new();
+
+        if let Some(connections) = &self.connections {
+            for conn in connections {
+                let src_key = node_key(&conn.loc);
+                if let Some(src_key) = &src_key {
+                    source_keys.insert(src_key.clone());
+                    known_apps.insert(src_key.1.clone(), src_key.0.clone());
+                }
+
+                for flows in [&conn.cmd, &conn.data, &conn.audio_frame, &conn.video_frame] {
+                    let Some(flows) = flows else {
+                        continue;
+                    };
+                    for flow in flows {
+                        for src in &flow.source {
+                            if let Some(key) = node_key(&src.loc) {
+                                known_apps.insert(key.1.clone(), key.0.clone());
+                                source_keys.insert(key);
+                            }
+                        }
+                        for dest in &flow.dest {
+                            if let Some(dest_key) = node_key(&dest.loc) {
+                                known_apps.insert(dest_key.1.clone(), dest_key.0.clone());
+                                dest_keys.insert(dest_key.clone());
+                                match &src_key {
+                                    Some(src_key) => {
+                                        adjacency.entry(src_key.clone()).or_default().push(dest_key.clone());
+                                        reverse_adjacency.entry(dest_key.clone()).or_default().push(src_key.clone());
+                                    }
+                                    None => {
+                                        unconditional_roots.insert(dest_key);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Entry nodes: declared sources that are never themselves a
+        // destination, plus unresolved-selector/subgraph destinations, plus
+        // extensions named directly by an `exposed_messages` `*In` entry.
+        let mut live: HashSet<NodeKey> = HashSet::new();
+        let mut worklist: VecDeque<NodeKey> = VecDeque::new();
+
+        let seed = |live: &mut HashSet<NodeKey>, worklist: &mut VecDeque<NodeKey>, key: NodeKey| {
+            if live.insert(key.clone()) {
+                worklist.push_back(key);
+            }
+        };
+
+        for key in source_keys.iter().filter(|k| !dest_keys.contains(*k)) {
+            seed(&mut live, &mut worklist, key.clone());
+        }
+        for key in &unconditional_roots {
+            seed(&mut live, &mut worklist, key.clone());
+        }
+        if let Some(exposed_messages) = &self.exposed_messages {
+            for exposed in exposed_messages {
+                let is_in = matches!(
+                    exposed.msg_type,
+                    GraphExposedMessageType::CmdIn
+                        | GraphExposedMessageType::DataIn
+                        | GraphExposedMessageType::AudioFrameIn
+                        | GraphExposedMessageType::VideoFrameIn
+                );
+                if !is_in {
+                    continue;
+                }
+                if let Some(extension) = &exposed.extension {
+                    let app = known_apps.get(extension).cloned().unwrap_or(None);
+                    seed(&mut live, &mut worklist, (app, extension.clone()));
+                }
+            }
+        }
+
+        while let Some(node) = worklist.pop_front() {
+            if let Some(out_edges) = adjacency.get(&node) {
+                for dest in out_edges {
+                    if live.insert(dest.clone()) {
+                        worklist.push_back(dest.clone());
+                    }
+                }
+            }
+        }
+
+        let mut unreachable_nodes = Vec::new();
+        for node in &self.nodes {
+            if node.get_type() != GraphNodeType::Extension {
+                continue;
+            }
+            let name = node.get_name().to_string();
+            let app = known_apps.get(&name).cloned().unwrap_or(None);
+            if !live.contains(&(app, name.clone())) {
+                unreachable_nodes.push(name);
+            }
+        }
+        unreachable_nodes.sort();
+
+        let mut orphan_flows = Vec::new();
+        if let Some(connections) = &self.connections {
+            for (idx, conn) in connections.iter().enumerate() {
+                for flows in [&conn.cmd, &conn.data, &conn.audio_frame, &conn.video_frame] {
+                    let Some(flows) = flows else {
+                        continue;
+                    };
+                    for flow in flows {
+                        if flow.dest.is_empty() {
+                            continue;
+                        }
+                        let all_dead_or_self = flow.dest.iter().all(|dest| match node_key(&dest.loc) {
+                            Some(dest_key) => !live.contains(&dest_key) || dest.loc.matches(&conn.loc),
+                            None => true,
+                        });
+                        if all_dead_or_self {
+                            orphan_flows.push(idx);
+                        }
+                    }
+                }
+            }
+        }
+        orphan_flows.dedup();
+
+        // Reverse pass: find extensions that can never reach an
+        // `exposed_messages` `*Out` root, i.e. whose output is always
+        // dropped. Only meaningful when the graph actually declares an
+        // escape boundary.
+        let mut out_roots: HashSet<NodeKey> = HashSet::new();
+        if let Some(exposed_messages) = &self.exposed_messages {
+            for exposed in exposed_messages {
+                let is_out = matches!(
+                    exposed.msg_type,
+                    GraphExposedMessageType::CmdOut
+                        | GraphExposedMessageType::DataOut
+                        | GraphExposedMessageType::AudioFrameOut
+                        | GraphExposedMessageType::VideoFrameOut
+                );
+                if !is_out {
+                    continue;
+                }
+                if let Some(extension) = &exposed.extension {
+                    let app = known_apps.get(extension).cloned().unwrap_or(None);
+                    out_roots.insert((app, extension.clone()));
+                }
+            }
+        }
+
+        let mut dead_output_nodes = Vec::new();
+        if !out_roots.is_empty() {
+            let mut can_escape: HashSet<NodeKey> = HashSet::new();
+            let mut worklist: VecDeque<NodeKey> = VecDeque::new();
+            for key in &out_roots {
+                if can_escape.insert(key.clone()) {
+                    worklist.push_back(key.clone());
+                }
+            }
+            while let Some(node) = worklist.pop_front() {
+                if let Some(in_edges) = reverse_adjacency.get(&node) {
+                    for src in in_edges {
+                        if can_escape.insert(src.clone()) {
+                            worklist.push_back(src.clone());
+                        }
+                    }
+                }
+            }
+
+            for node in &self.nodes {
+                if node.get_type() != GraphNodeType::Extension {
+                    continue;
+                }
+                let name = node.get_name().to_string();
+                let app = known_apps.get(&name).cloned().unwrap_or(None);
+                if !can_escape.contains(&(app, name.clone())) {
+                    dead_output_nodes.push(name);
+                }
+            }
+            dead_output_nodes.sort();
+        }
+
+        ReachabilityReport {
+            unreachable_nodes,
+            orphan_flows,
+            dead_output_nodes,
+        }
+    }
+}
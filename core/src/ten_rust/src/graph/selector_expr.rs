@@ -0,0 +1,109 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::graph::{
+    node::{GraphNode, GraphNodeType},
+    Graph,
+};
+
+/// Matches nodes by attribute: declared addon, app URI, or both. An
+/// unspecified field is treated as "don't care".
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct SelectorPredicate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub addon: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app: Option<String>,
+}
+
+impl SelectorPredicate {
+    fn matches(&self, node: &GraphNode) -> bool {
+        if let Some(addon) = &self.addon {
+            if node.get_addon().map(|n| n.as_str()) != Some(addon.as_str()) {
+                return false;
+            }
+        }
+
+        if let Some(app) = &self.app {
+            if node.get_app_uri().as_deref() != Some(app.as_str()) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// A selector expression combining attribute predicates with set algebra
+/// (`all_of` for intersection, `any_of` for union, `not` for complement)
+/// over the nodes declared in a graph.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum SelectorExpr {
+    AllOf {
+        all_of: Vec<SelectorExpr>,
+    },
+    AnyOf {
+        any_of: Vec<SelectorExpr>,
+    },
+    Not {
+        not: Box<SelectorExpr>,
+    },
+    Predicate(SelectorPredicate),
+}
+
+impl SelectorExpr {
+    fn matches(&self, node: &GraphNode) -> bool {
+        match self {
+            SelectorExpr::AllOf {
+                all_of,
+            } => all_of.iter().all(|expr| expr.matches(node)),
+            SelectorExpr::AnyOf {
+                any_of,
+            } => any_of.iter().any(|expr| expr.matches(node)),
+            SelectorExpr::Not {
+                not,
+            } => !not.matches(node),
+            SelectorExpr::Predicate(predicate) => predicate.matches(node),
+        }
+    }
+}
+
+impl Graph {
+    /// Evaluates a selector expression against the nodes declared in this
+    /// graph, returning matches in stable declaration order.
+    pub fn evaluate_selector_expr(&self, expr: &SelectorExpr) -> Vec<&GraphNode> {
+        self.nodes.iter().filter(|node| expr.matches(node)).collect()
+    }
+
+    /// Looks up the `selector`-typed node named `selector_name` and
+    /// evaluates its expression against the graph's extension nodes.
+    ///
+    /// Errors if no selector node with that name is declared, so a
+    /// `GraphLoc.selector` referencing a name that doesn't exist is caught
+    /// rather than silently expanding to nothing.
+    pub fn get_nodes_by_selector_node_name(&self, selector_name: &str) -> Result<Vec<&GraphNode>> {
+        let selector_node = self
+            .nodes
+            .iter()
+            .find(|node| node.get_type() == GraphNodeType::Selector && node.get_name() == selector_name)
+            .ok_or_else(|| anyhow::anyhow!("no selector node named '{}' is declared in the graph", selector_name))?;
+
+        let expr = selector_node
+            .get_selector_expr()
+            .ok_or_else(|| anyhow::anyhow!("selector node '{}' has no selector expression", selector_name))?;
+
+        Ok(self
+            .evaluate_selector_expr(expr)
+            .into_iter()
+            .filter(|node| node.get_type() == GraphNodeType::Extension)
+            .collect())
+    }
+}
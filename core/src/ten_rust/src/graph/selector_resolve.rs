@@ -0,0 +1,104 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+use anyhow::Result;
+
+use crate::graph::{
+    connection::{GraphDestination, GraphLoc, GraphSource},
+    node::GraphNodeType,
+    Graph,
+};
+
+impl Graph {
+    /// Rewrites every `GraphLoc.selector` reference in `connections` (a
+    /// connection's own `loc`, a flow's `source`, or a destination's `loc`)
+    /// into one literal extension `GraphLoc` per node that
+    /// `get_nodes_by_selector_node_name` returns, so e.g. "send
+    /// `tts_complete` to every extension whose addon is `logger_addon` or
+    /// `recorder_addon`" becomes one concrete destination per matching
+    /// extension instead of requiring every one to be enumerated by hand.
+    /// Selectors match on addon and app URI only; a node's group is not a
+    /// selectable attribute.
+    ///
+    /// This performs the same selector fan-out that full graph flattening
+    /// applies, but as a standalone, fail-fast step: it errors immediately if
+    /// any selector matches zero nodes, without running the rest of graph
+    /// validation.
+    pub fn resolve_selectors(&self) -> Result<Graph> {
+        let mut resolved = self.clone();
+
+        let Some(connections) = &self.connections else {
+            return Ok(resolved);
+        };
+
+        let mut new_connections = Vec::new();
+        for conn in connections {
+            for src_loc in self.expand_selector_loc(&conn.loc)? {
+                let mut new_conn = conn.clone();
+                new_conn.loc = src_loc;
+
+                for flows in
+                    [&mut new_conn.cmd, &mut new_conn.data, &mut new_conn.audio_frame, &mut new_conn.video_frame]
+                {
+                    let Some(flows) = flows else {
+                        continue;
+                    };
+
+                    for flow in flows.iter_mut() {
+                        let mut expanded_dest = Vec::with_capacity(flow.dest.len());
+                        for dest in &flow.dest {
+                            for loc in self.expand_selector_loc(&dest.loc)? {
+                                expanded_dest.push(GraphDestination {
+                                    loc,
+                                    msg_conversion: dest.msg_conversion.clone(),
+                                });
+                            }
+                        }
+                        flow.dest = expanded_dest;
+
+                        let mut expanded_source = Vec::with_capacity(flow.source.len());
+                        for source in &flow.source {
+                            for loc in self.expand_selector_loc(&source.loc)? {
+                                expanded_source.push(GraphSource {
+                                    loc,
+                                });
+                            }
+                        }
+                        flow.source = expanded_source;
+                    }
+                }
+
+                new_connections.push(new_conn);
+            }
+        }
+
+        resolved.connections = Some(new_connections);
+        Ok(resolved)
+    }
+
+    /// Expands a single `GraphLoc`: unchanged if it doesn't carry a
+    /// `selector`, otherwise one literal extension `GraphLoc` per node the
+    /// named selector matches. Errors if the selector matches zero nodes,
+    /// since a fan-out rule that resolves to nothing is almost always a
+    /// graph-authoring mistake rather than intentional.
+    fn expand_selector_loc(&self, loc: &GraphLoc) -> Result<Vec<GraphLoc>> {
+        let Some(selector_name) = &loc.selector else {
+            return Ok(vec![loc.clone()]);
+        };
+
+        let nodes = self.get_nodes_by_selector_node_name(selector_name)?;
+        if nodes.is_empty() {
+            return Err(anyhow::anyhow!("selector '{}' matched zero nodes", selector_name));
+        }
+
+        nodes
+            .iter()
+            .map(|node| {
+                GraphLoc::with_app_and_type_and_name(loc.app.clone(), GraphNodeType::Extension, node.get_name().to_string())
+            })
+            .collect()
+    }
+}
@@ -6,6 +6,8 @@
 //
 pub mod flatten;
 
+use std::collections::HashSet;
+
 use anyhow::Result;
 
 use crate::{
@@ -16,9 +18,31 @@ use crate::{
         Graph, GraphExposedMessageType, GraphNodeType,
     },
     pkg_info::message::{MsgDirection, MsgType},
-    utils::path::{get_base_dir_of_uri, get_real_path_from_import_uri},
+    utils::{
+        integrity::IntegrityLockfile,
+        path::{get_base_dir_of_uri, resolve_import_uri, ImportMap, ImportPolicy},
+    },
 };
 
+/// Bounds worst-case recursion when resolving nested subgraphs, matching how
+/// module-graph builders in the Deno references guard their traversal
+/// against runaway depth.
+const MAX_SUBGRAPH_RESOLUTION_DEPTH: usize = 32;
+
+/// Resolves a subgraph's `import_uri`, consulting `import_map` first (when
+/// given) so an aliased/remapped target is what cycle detection and
+/// `load_graph_from_uri` both see, instead of the raw un-remapped specifier,
+/// then enforcing `policy` (when given) against the resolved URL so a nested
+/// subgraph can't silently pull its definition from a host the policy denies.
+fn resolve_subgraph_import_uri(
+    import_uri: &str,
+    base_dir: Option<&str>,
+    import_map: Option<&ImportMap>,
+    policy: Option<&ImportPolicy>,
+) -> Result<String> {
+    resolve_import_uri(import_uri, base_dir, None, import_map, policy)
+}
+
 impl Graph {
     /// Helper function to resolve subgraph reference to actual extension name.
     /// This function looks up the exposed_messages in the subgraph to find
@@ -74,7 +98,14 @@ impl Graph {
         }
     }
 
-    /// Helper function to get addon name for both extension and subgraph nodes
+    /// Helper function to get addon name for both extension and subgraph
+    /// nodes. `import_map` and `policy` are forwarded to the subgraph loader
+    /// so a message-flow lookup that has to load a nested subgraph remaps
+    /// and vets its `import_uri` the same way the rest of graph resolution
+    /// does. `integrity` is forwarded the same way, so a fetched subgraph is
+    /// checked against (or recorded into) the caller's integrity lockfile,
+    /// when one is supplied.
+    #[allow(clippy::too_many_arguments)]
     pub async fn get_addon_name_of_node(
         &self,
         base_dir: &Option<String>,
@@ -82,6 +113,9 @@ impl Graph {
         msg_type: &crate::pkg_info::message::MsgType,
         msg_name: &str,
         msg_direction: MsgDirection,
+        import_map: Option<&ImportMap>,
+        policy: Option<&ImportPolicy>,
+        integrity: &mut Option<IntegrityLockfile>,
     ) -> Result<String> {
         match loc.get_node_type()? {
             GraphNodeType::Extension => {
@@ -97,6 +131,9 @@ impl Graph {
                         msg_type,
                         msg_name,
                         msg_direction,
+                        import_map,
+                        policy,
+                        integrity,
                     )
                     .await?;
                 Ok(extension_node.addon)
@@ -111,7 +148,10 @@ impl Graph {
     /// Recursively finds an extension node from a subgraph using exposed
     /// message. This function handles nested subgraphs by recursively
     /// searching until it finds the actual extension node, not another
-    /// subgraph.
+    /// subgraph. `import_map` and `policy` are applied to every nested
+    /// subgraph's `import_uri`, same as
+    /// `collect_subgraph_member_extension_names`.
+    #[allow(clippy::too_many_arguments)]
     pub async fn get_extension_node_from_subgraph_using_exposed_message(
         &self,
         base_dir: &Option<String>,
@@ -119,7 +159,53 @@ impl Graph {
         msg_type: &MsgType,
         msg_name: &str,
         msg_direction: MsgDirection,
+        import_map: Option<&ImportMap>,
+        policy: Option<&ImportPolicy>,
+        integrity: &mut Option<IntegrityLockfile>,
     ) -> Result<ExtensionNode> {
+        let mut visited = HashSet::new();
+        self.get_extension_node_from_subgraph_using_exposed_message_guarded(
+            base_dir,
+            subgraph_name,
+            msg_type,
+            msg_name,
+            msg_direction,
+            import_map,
+            policy,
+            integrity,
+            &mut visited,
+            0,
+        )
+        .await
+    }
+
+    /// Same as `get_extension_node_from_subgraph_using_exposed_message`, but
+    /// threads a visited-set of fully-resolved subgraph URIs and a depth
+    /// counter through the recursion, so a subgraph that (directly or
+    /// transitively) imports itself fails fast with a structured diagnostic
+    /// instead of recursing forever.
+    #[allow(clippy::too_many_arguments)]
+    async fn get_extension_node_from_subgraph_using_exposed_message_guarded(
+        &self,
+        base_dir: &Option<String>,
+        subgraph_name: &str,
+        msg_type: &MsgType,
+        msg_name: &str,
+        msg_direction: MsgDirection,
+        import_map: Option<&ImportMap>,
+        policy: Option<&ImportPolicy>,
+        integrity: &mut Option<IntegrityLockfile>,
+        visited: &mut HashSet<String>,
+        depth: usize,
+    ) -> Result<ExtensionNode> {
+        if depth >= MAX_SUBGRAPH_RESOLUTION_DEPTH {
+            return Err(anyhow::anyhow!(
+                "subgraph resolution exceeded max depth of {} while resolving '{}'",
+                MAX_SUBGRAPH_RESOLUTION_DEPTH,
+                subgraph_name
+            ));
+        }
+
         // Find the subgraph node
         let subgraph_node = self
             .nodes
@@ -164,13 +250,44 @@ impl Graph {
             }
         };
 
-        // Load the subgraph from the import_uri
-        let subgraph_graph =
-            load_graph_from_uri(&subgraph_content.graph.import_uri, base_dir.as_deref(), &mut None)
-                .await
-                .map_err(|e| {
-                    anyhow::anyhow!("Failed to load subgraph '{}': {}", subgraph_name, e)
-                })?;
+        // Detect an import cycle before loading: if the resolved URI has
+        // already been visited on this recursion path, fail fast with a
+        // clear diagnostic instead of recursing (and eventually stack
+        // overflowing) on a malformed or maliciously circular graph.
+        let resolved_uri = resolve_subgraph_import_uri(
+            &subgraph_content.graph.import_uri,
+            base_dir.as_deref(),
+            import_map,
+            policy,
+        )?;
+        if !visited.insert(resolved_uri.clone()) {
+            return Err(anyhow::anyhow!(
+                "cycle detected while resolving subgraph '{}': '{}' is already part of the current \
+                 import chain",
+                subgraph_name,
+                resolved_uri
+            ));
+        }
+
+        // Load the subgraph from `resolved_uri` -- the import-map-remapped,
+        // policy-checked URI -- not the raw `import_uri`, so the map/policy
+        // actually govern what gets loaded instead of only what cycle
+        // detection sees. If resolution itself failed with a structured
+        // `ModuleResolutionError`, append this subgraph as a frame in the
+        // import chain so the final message reads e.g. "... not found,
+        // imported from subgraph 'audio' (file:///.../audio.json), imported
+        // from root" instead of a single flat message.
+        let subgraph_graph = load_graph_from_uri(&resolved_uri, base_dir.as_deref(), integrity)
+            .await
+            .map_err(|e| match e.downcast::<crate::utils::path::ModuleResolutionError>() {
+            Ok(resolution_err) => anyhow::anyhow!(resolution_err.with_chain_frame(format!(
+                "subgraph '{}' ({})",
+                subgraph_name, subgraph_content.graph.import_uri
+            ))),
+            Err(other) => {
+                anyhow::anyhow!("Failed to load subgraph '{}': {}", subgraph_name, other)
+            }
+        })?;
 
         // Find the extension specified by the exposed message
         let extension_name = Self::resolve_subgraph_to_extension(
@@ -202,19 +319,19 @@ impl Graph {
                 content,
             } = node
             {
-                let real_path = get_real_path_from_import_uri(
-                    &subgraph_content.graph.import_uri,
-                    base_dir.as_deref(),
-                    None,
-                )?;
-                let nested_base_dir = Some(get_base_dir_of_uri(&real_path)?);
+                let nested_base_dir = Some(get_base_dir_of_uri(&resolved_uri)?);
                 return Box::pin(
-                    subgraph_graph.get_extension_node_from_subgraph_using_exposed_message(
+                    subgraph_graph.get_extension_node_from_subgraph_using_exposed_message_guarded(
                         &nested_base_dir,
                         &content.name,
                         msg_type,
                         msg_name,
                         msg_direction,
+                        import_map,
+                        policy,
+                        integrity,
+                        visited,
+                        depth + 1,
                     ),
                 )
                 .await;
@@ -229,3 +346,220 @@ impl Graph {
         ))
     }
 }
+
+/// Recursively collects the names of every extension node a `subgraph` node
+/// (transitively) expands to, in declaration order. Used by
+/// `GraphContent::write_lock` to record exactly which extensions a subgraph
+/// resolves to, the same way a `selector` node's members are recorded via
+/// `get_nodes_by_selector_node_name`.
+///
+/// `nodes` is the pre-flatten node list to look `subgraph_name` up in (a
+/// `Graph`'s or `GraphContent`'s own `nodes` field). `import_map` and
+/// `policy` are applied to every nested subgraph's `import_uri` the same way
+/// `get_extension_node_from_subgraph_using_exposed_message` does; pass
+/// `None` when the caller has no map/policy in scope. `integrity` is
+/// forwarded to `load_graph_from_uri` the same way; pass `&mut None` when
+/// the caller has no integrity lockfile to check against.
+#[allow(clippy::too_many_arguments)]
+pub async fn collect_subgraph_member_extension_names(
+    nodes: &[GraphNode],
+    base_dir: &Option<String>,
+    subgraph_name: &str,
+    import_map: Option<&ImportMap>,
+    policy: Option<&ImportPolicy>,
+    integrity: &mut Option<IntegrityLockfile>,
+) -> Result<Vec<String>> {
+    let mut visited = HashSet::new();
+    let mut members = Vec::new();
+    collect_subgraph_member_extension_names_guarded(
+        nodes,
+        base_dir,
+        subgraph_name,
+        import_map,
+        policy,
+        integrity,
+        &mut visited,
+        &mut members,
+        0,
+    )
+    .await?;
+    Ok(members)
+}
+
+/// Same as `collect_subgraph_member_extension_names`, but threads a
+/// visited-set of fully-resolved subgraph URIs and a depth counter through
+/// the recursion, mirroring
+/// `get_extension_node_from_subgraph_using_exposed_message_guarded`'s cycle
+/// and depth guards.
+#[allow(clippy::too_many_arguments)]
+async fn collect_subgraph_member_extension_names_guarded(
+    nodes: &[GraphNode],
+    base_dir: &Option<String>,
+    subgraph_name: &str,
+    import_map: Option<&ImportMap>,
+    policy: Option<&ImportPolicy>,
+    integrity: &mut Option<IntegrityLockfile>,
+    visited: &mut HashSet<String>,
+    members: &mut Vec<String>,
+    depth: usize,
+) -> Result<()> {
+    if depth >= MAX_SUBGRAPH_RESOLUTION_DEPTH {
+        return Err(anyhow::anyhow!(
+            "subgraph resolution exceeded max depth of {} while resolving '{}'",
+            MAX_SUBGRAPH_RESOLUTION_DEPTH,
+            subgraph_name
+        ));
+    }
+
+    let subgraph_node = nodes
+        .iter()
+        .find(|node| node.get_type() == GraphNodeType::Subgraph && node.get_name() == subgraph_name)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Subgraph '{}' is not found in nodes, should not happen.",
+                subgraph_name
+            )
+        })?;
+
+    let subgraph_content = if let GraphNode::Subgraph { content } = subgraph_node {
+        content
+    } else {
+        return Err(anyhow::anyhow!(
+            "Node '{}' is not a subgraph node, should not happen.",
+            subgraph_name
+        ));
+    };
+
+    let resolved_uri = resolve_subgraph_import_uri(
+        &subgraph_content.graph.import_uri,
+        base_dir.as_deref(),
+        import_map,
+        policy,
+    )?;
+    if !visited.insert(resolved_uri.clone()) {
+        return Err(anyhow::anyhow!(
+            "cycle detected while resolving subgraph '{}': '{}' is already part of the current \
+             import chain",
+            subgraph_name,
+            resolved_uri
+        ));
+    }
+
+    // Load from `resolved_uri`, not the raw `import_uri`, so the map/policy
+    // applied above actually govern what gets loaded.
+    let subgraph_graph = load_graph_from_uri(&resolved_uri, base_dir.as_deref(), integrity).await?;
+    let nested_base_dir = Some(get_base_dir_of_uri(&resolved_uri)?);
+
+    for node in &subgraph_graph.nodes {
+        match node {
+            GraphNode::Extension { content } => members.push(content.name.clone()),
+            GraphNode::Subgraph { content } => {
+                Box::pin(collect_subgraph_member_extension_names_guarded(
+                    &subgraph_graph.nodes,
+                    &nested_base_dir,
+                    &content.name,
+                    import_map,
+                    policy,
+                    integrity,
+                    visited,
+                    members,
+                    depth + 1,
+                ))
+                .await?;
+            }
+            _ => {}
+        }
+    }
+
+    // Leaving this subgraph: it is no longer on the current import chain, so
+    // a sibling that imports the same URI is not mistaken for a cycle.
+    visited.remove(&resolved_uri);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `resolve_subgraph_import_uri` is private, so it can only be exercised
+    // from an in-module test; both `load_graph_from_uri` call sites above
+    // now load from exactly the value it returns, so these tests pin what
+    // that value is instead of only checking it's computed and then
+    // discarded.
+
+    #[test]
+    fn test_import_map_remap_is_the_uri_that_gets_loaded() {
+        let map: ImportMap = serde_json::from_value(serde_json::json!({
+            "imports": {
+                "shared/sub.json": "file:///vendor/mapped_sub.json"
+            }
+        }))
+        .unwrap();
+
+        let resolved =
+            resolve_subgraph_import_uri("shared/sub.json", None, Some(&map), None).unwrap();
+
+        // Before this fix, the call sites re-used the raw `import_uri`
+        // ("shared/sub.json") for the actual `load_graph_from_uri` call, so
+        // the import map was only ever consulted for cycle detection.
+        assert_eq!(resolved, "file:///vendor/mapped_sub.json");
+    }
+
+    #[test]
+    fn test_no_import_map_resolves_the_original_specifier() {
+        let resolved =
+            resolve_subgraph_import_uri("sub.json", Some("/home/user/app"), None, None).unwrap();
+        assert_eq!(resolved, "/home/user/app/sub.json");
+    }
+
+    // Now that both call sites load from `resolved_uri`, `policy` vets the
+    // same URI the loader actually fetches -- so a denying policy can't be
+    // bypassed by an import-map entry that remaps to a host the policy
+    // would have allowed, and a permissive policy doesn't accidentally
+    // bless the pre-remap specifier instead of the real target.
+
+    #[test]
+    fn test_policy_denies_the_import_map_remapped_host_even_though_the_original_specifier_is_local() {
+        let map: ImportMap = serde_json::from_value(serde_json::json!({
+            "imports": {
+                "shared/": "http://evil.example.com/"
+            }
+        }))
+        .unwrap();
+        let policy = ImportPolicy::new();
+
+        let result = resolve_subgraph_import_uri("shared/sub.json", None, Some(&map), Some(&policy));
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("is not permitted"));
+    }
+
+    #[test]
+    fn test_policy_allows_the_import_map_remapped_host_when_whitelisted() {
+        let map: ImportMap = serde_json::from_value(serde_json::json!({
+            "imports": {
+                "shared/": "http://trusted.example.com/"
+            }
+        }))
+        .unwrap();
+        let mut policy = ImportPolicy::new();
+        policy.allow_host("trusted.example.com:80");
+
+        let resolved =
+            resolve_subgraph_import_uri("shared/sub.json", None, Some(&map), Some(&policy)).unwrap();
+
+        assert_eq!(resolved, "http://trusted.example.com/sub.json");
+    }
+
+    #[test]
+    fn test_policy_without_a_map_is_checked_against_the_original_specifier() {
+        let policy = ImportPolicy::new();
+
+        let result =
+            resolve_subgraph_import_uri("http://evil.example.com/sub.json", None, None, Some(&policy));
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("is not permitted"));
+    }
+}
@@ -0,0 +1,245 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+use anyhow::Result;
+
+use crate::{
+    graph::{
+        connection::{GraphConnection, GraphLoc},
+        msg_conversion::MsgAndResultConversion,
+        node::GraphNode,
+        Graph,
+    },
+    pkg_info::message::MsgType,
+};
+
+/// A single recorded edit against a `Graph`. Mirrors the five operations a
+/// caller building a graph from a spec actually needs: add/remove a whole
+/// connection entry, add/remove a node, or repoint a single destination's
+/// message conversion.
+///
+/// `RemoveConnectionAt`/`RemoveNodeAt` target a slot by index rather than by
+/// `GraphLoc`: two `GraphConnection`s (or nodes) can legitimately share a
+/// `loc`, and a loc-keyed removal can't tell them apart -- it would remove
+/// every matching entry instead of exactly one. `remove_connection`/
+/// `remove_node` resolve the public loc-based request to a concrete index up
+/// front, so exactly one entry is ever touched, the same entry every time.
+#[derive(Debug, Clone)]
+enum GraphEditOp {
+    AddConnection(GraphConnection),
+    RemoveConnectionAt(usize),
+    AddNode(GraphNode),
+    RemoveNodeAt(usize),
+    SetMsgConversion {
+        src: GraphLoc,
+        msg_type: MsgType,
+        msg_name: String,
+        dest: GraphLoc,
+        msg_conversion: Option<MsgAndResultConversion>,
+    },
+}
+
+fn node_loc(node: &GraphNode) -> Result<GraphLoc> {
+    match node {
+        GraphNode::Extension {
+            content,
+        } => GraphLoc::with_app_and_type_and_name(
+            content.app.clone(),
+            crate::graph::GraphNodeType::Extension,
+            content.name.clone(),
+        ),
+        GraphNode::Subgraph {
+            content,
+        } => GraphLoc::with_app_and_type_and_name(
+            None,
+            crate::graph::GraphNodeType::Subgraph,
+            content.name.clone(),
+        ),
+    }
+}
+
+fn msg_flows_mut(
+    connection: &mut GraphConnection,
+    msg_type: MsgType,
+) -> &mut Option<Vec<crate::graph::connection::GraphMessageFlow>> {
+    match msg_type {
+        MsgType::Cmd => &mut connection.cmd,
+        MsgType::Data => &mut connection.data,
+        MsgType::AudioFrame => &mut connection.audio_frame,
+        MsgType::VideoFrame => &mut connection.video_frame,
+    }
+}
+
+/// An all-or-nothing batch of edits against a `Graph`: every op method
+/// applies immediately, but `begin()` snapshots `nodes`/`connections` up
+/// front so that `commit()` (on validation failure) or an explicit
+/// `rollback()` can restore the graph to exactly its pre-transaction state
+/// in one assignment.
+///
+/// An earlier version of this type tracked rollback via a log of per-op
+/// inverses (e.g. "undo this `AddConnection` by removing index 3") replayed
+/// in reverse. That broke as soon as ops of different kinds interleaved:
+/// an `AddConnection`'s inverse index is only valid until some other op
+/// shifts the vector, and `RemoveConnectionAt`/`RemoveNodeAt` inverses
+/// always re-append rather than reinserting at the original slot. Snapshot
+/// restore sidesteps all of that by never depending on indices surviving
+/// across ops.
+pub struct GraphTransaction<'a> {
+    graph: &'a mut Graph,
+    original_nodes: Vec<GraphNode>,
+    original_connections: Option<Vec<GraphConnection>>,
+}
+
+impl Graph {
+    /// Starts a transaction over `self`. Every op method applies
+    /// immediately; call `commit()` to validate once and keep the result, or
+    /// `rollback()` to undo everything applied so far.
+    pub fn begin(&mut self) -> GraphTransaction<'_> {
+        GraphTransaction {
+            original_nodes: self.nodes.clone(),
+            original_connections: self.connections.clone(),
+            graph: self,
+        }
+    }
+}
+
+impl<'a> GraphTransaction<'a> {
+    fn apply_forward(&mut self, op: GraphEditOp) -> Result<()> {
+        match op {
+            GraphEditOp::AddConnection(conn) => {
+                self.graph.connections.get_or_insert_with(Vec::new).push(conn);
+            }
+            GraphEditOp::RemoveConnectionAt(index) => {
+                let connections = self.graph.connections.get_or_insert_with(Vec::new);
+                if index >= connections.len() {
+                    return Err(anyhow::anyhow!("no connection at index {}", index));
+                }
+                connections.remove(index);
+            }
+            GraphEditOp::AddNode(node) => {
+                self.graph.nodes.push(node);
+            }
+            GraphEditOp::RemoveNodeAt(index) => {
+                if index >= self.graph.nodes.len() {
+                    return Err(anyhow::anyhow!("no node at index {}", index));
+                }
+                self.graph.nodes.remove(index);
+            }
+            GraphEditOp::SetMsgConversion {
+                src,
+                msg_type,
+                msg_name,
+                dest,
+                msg_conversion,
+            } => {
+                let connection = self
+                    .graph
+                    .connections
+                    .as_mut()
+                    .and_then(|conns| conns.iter_mut().find(|conn| conn.loc.matches(&src)))
+                    .ok_or_else(|| anyhow::anyhow!("no connection found for loc {:?}", src))?;
+
+                let flow = msg_flows_mut(connection, msg_type)
+                    .as_mut()
+                    .and_then(|flows| flows.iter_mut().find(|flow| flow.name.as_deref() == Some(msg_name.as_str())))
+                    .ok_or_else(|| anyhow::anyhow!("no message flow named '{}' found", msg_name))?;
+
+                let destination = flow
+                    .dest
+                    .iter_mut()
+                    .find(|d| d.loc.matches(&dest))
+                    .ok_or_else(|| anyhow::anyhow!("no destination found for loc {:?}", dest))?;
+
+                destination.msg_conversion = msg_conversion;
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds a whole `GraphConnection` entry.
+    pub fn add_connection(&mut self, connection: GraphConnection) -> Result<&mut Self> {
+        self.apply_forward(GraphEditOp::AddConnection(connection))?;
+        Ok(self)
+    }
+
+    /// Removes the `GraphConnection` entry whose `loc` matches `src`. If
+    /// more than one entry shares that `loc`, only the first one (in
+    /// declaration order) is removed.
+    pub fn remove_connection(&mut self, src: GraphLoc) -> Result<&mut Self> {
+        let connections = self.graph.connections.as_ref().map(|v| v.as_slice()).unwrap_or(&[]);
+        let index = connections
+            .iter()
+            .position(|conn| conn.loc.matches(&src))
+            .ok_or_else(|| anyhow::anyhow!("no connection found for loc {:?}", src))?;
+        self.apply_forward(GraphEditOp::RemoveConnectionAt(index))?;
+        Ok(self)
+    }
+
+    /// Adds a node.
+    pub fn add_node(&mut self, node: GraphNode) -> Result<&mut Self> {
+        self.apply_forward(GraphEditOp::AddNode(node))?;
+        Ok(self)
+    }
+
+    /// Removes the node at `loc`. If more than one node shares that `loc`,
+    /// only the first one (in declaration order) is removed.
+    pub fn remove_node(&mut self, loc: GraphLoc) -> Result<&mut Self> {
+        let index = self
+            .graph
+            .nodes
+            .iter()
+            .position(|node| node_loc(node).map(|node_loc| node_loc.matches(&loc)).unwrap_or(false))
+            .ok_or_else(|| anyhow::anyhow!("no node found for loc {:?}", loc))?;
+        self.apply_forward(GraphEditOp::RemoveNodeAt(index))?;
+        Ok(self)
+    }
+
+    /// Repoints the message conversion of an existing destination.
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_msg_conversion(
+        &mut self,
+        src: GraphLoc,
+        msg_type: MsgType,
+        msg_name: String,
+        dest: GraphLoc,
+        msg_conversion: Option<MsgAndResultConversion>,
+    ) -> Result<&mut Self> {
+        self.apply_forward(GraphEditOp::SetMsgConversion {
+            src,
+            msg_type,
+            msg_name,
+            dest,
+            msg_conversion,
+        })?;
+        Ok(self)
+    }
+
+    /// Restores `self.graph`'s nodes and connections to their state as of
+    /// `begin()`, discarding every op applied since.
+    fn undo_all(&mut self) {
+        self.graph.nodes = std::mem::take(&mut self.original_nodes);
+        self.graph.connections = std::mem::take(&mut self.original_connections);
+    }
+
+    /// Validates the graph once and keeps every applied op on success; on
+    /// failure, restores the pre-transaction snapshot and returns the
+    /// validation error.
+    pub fn commit(mut self) -> Result<()> {
+        match self.graph.validate_and_complete(None) {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                self.undo_all();
+                Err(e)
+            }
+        }
+    }
+
+    /// Undoes every op applied so far, regardless of validity, restoring the
+    /// graph to its state as of `begin()`.
+    pub fn rollback(mut self) {
+        self.undo_all();
+    }
+}
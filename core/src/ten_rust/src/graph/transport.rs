@@ -0,0 +1,132 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::graph::{node::GraphNode, Graph};
+
+/// The node name `inject_graph_proxy_from_exposed_messages` uses for its
+/// synthetic cross-graph forwarding node.
+const GRAPH_PROXY_NODE_NAME: &str = "ten:graph_proxy";
+
+/// A transport a `ten:graph_proxy` connection can reach the remote app over.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum GraphProxyTransportKind {
+    #[serde(rename = "websocket")]
+    WebSocket,
+    Tcp,
+    InProcess,
+}
+
+/// The message channel kinds a negotiated transport bundles onto one link,
+/// mirroring the `cmd`/`data`/`audio_frame`/`video_frame` flows a connection
+/// already distinguishes.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum GraphProxyChannelKind {
+    Cmd,
+    Data,
+    AudioFrame,
+    VideoFrame,
+}
+
+impl GraphProxyChannelKind {
+    /// All channel kinds a single negotiated transport link carries.
+    const ALL: [GraphProxyChannelKind; 4] = [
+        GraphProxyChannelKind::Cmd,
+        GraphProxyChannelKind::Data,
+        GraphProxyChannelKind::AudioFrame,
+        GraphProxyChannelKind::VideoFrame,
+    ];
+}
+
+/// The outcome of negotiating a `ten:graph_proxy` node's transport: the
+/// chosen transport plus the channel kinds bundled onto it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct GraphProxyTransportNegotiation {
+    pub transport: GraphProxyTransportKind,
+    pub channels: Vec<GraphProxyChannelKind>,
+}
+
+/// Picks the first transport in `offered` (the offerer's candidates, in
+/// preference order) that also appears in `supported` (the answerer's
+/// capabilities), falling back down the list the same way session/transport
+/// offer-answer negotiation does. Returns `None` if no candidate is mutually
+/// supported.
+pub fn negotiate_transport(
+    offered: &[GraphProxyTransportKind],
+    supported: &[GraphProxyTransportKind],
+) -> Option<GraphProxyTransportKind> {
+    offered.iter().find(|candidate| supported.contains(candidate)).copied()
+}
+
+impl Graph {
+    /// Negotiates a transport for this graph's injected `ten:graph_proxy`
+    /// node. `host_loc.transports` (set by
+    /// `inject_graph_proxy_from_exposed_messages`'s caller) lists the remote
+    /// app's candidate transports in preference order; this picks the first
+    /// one also present in `supported`, bundles every message channel
+    /// (`cmd`/`data`/`audio_frame`/`video_frame`) onto that single link, and
+    /// records the result back onto `host_loc.transport`/`host_loc.channels`
+    /// so the proxy and its remote peer agree on one link for the whole
+    /// connection instead of negotiating per message class.
+    ///
+    /// A no-op if the graph has no `ten:graph_proxy` node, or its `host_loc`
+    /// doesn't declare `transports`. Errors if `transports` is declared but
+    /// none of its entries are mutually supported.
+    pub fn negotiate_graph_proxy_transport(&mut self, supported: &[GraphProxyTransportKind]) -> Result<()> {
+        let Some(node) = self.nodes.iter_mut().find(|n| n.get_name() == GRAPH_PROXY_NODE_NAME) else {
+            return Ok(());
+        };
+
+        let GraphNode::Extension {
+            content,
+        } = node
+        else {
+            return Ok(());
+        };
+
+        let Some(property) = content.property.as_mut() else {
+            return Ok(());
+        };
+
+        let Some(host_loc) = property.get_mut("host_loc") else {
+            return Ok(());
+        };
+
+        let Some(offered_raw) = host_loc.get("transports") else {
+            return Ok(());
+        };
+
+        let offered: Vec<GraphProxyTransportKind> = serde_json::from_value(offered_raw.clone())
+            .map_err(|e| anyhow::anyhow!("invalid host_loc.transports: {}", e))?;
+
+        let transport = negotiate_transport(&offered, supported).ok_or_else(|| {
+            anyhow::anyhow!(
+                "none of host_loc.transports {:?} are supported by this runtime (supported: {:?})",
+                offered,
+                supported
+            )
+        })?;
+
+        let negotiation = GraphProxyTransportNegotiation {
+            transport,
+            channels: GraphProxyChannelKind::ALL.to_vec(),
+        };
+
+        let Value::Object(host_loc_obj) = host_loc else {
+            return Ok(());
+        };
+
+        host_loc_obj.insert("transport".to_string(), serde_json::to_value(negotiation.transport)?);
+        host_loc_obj.insert("channels".to_string(), serde_json::to_value(&negotiation.channels)?);
+
+        Ok(())
+    }
+}
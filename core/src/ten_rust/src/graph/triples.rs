@@ -0,0 +1,120 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+use crate::graph::Graph;
+
+/// Escapes a string so it can be embedded as an N-Triples literal.
+fn escape_literal(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn node_iri(name: &str) -> String {
+    format!("ext:{}", name)
+}
+
+/// Builds the IRI for the reified edge a single `(kind, src, dest)` pair in
+/// a given message flow represents, so predicates specific to that edge
+/// (like `flow:name`) can be attached to the edge itself rather than to
+/// either endpoint node, which would otherwise conflate multiple distinctly
+/// named flows landing on (or leaving) the same destination.
+fn edge_iri(kind: &str, src_name: &str, dest_name: &str, flow_idx: usize) -> String {
+    format!("edge:{}:{}:{}:{}", kind, src_name, dest_name, flow_idx)
+}
+
+/// Returns a flow's message name(s): the single `name` when set, otherwise
+/// every entry of `names`, so a multi-name flow still emits one `flow:name`
+/// triple per message instead of being silently dropped.
+fn flow_names(name: &Option<String>, names: &Option<Vec<String>>) -> Vec<&str> {
+    match (name, names) {
+        (Some(name), _) => vec![name.as_str()],
+        (None, Some(names)) => names.iter().map(String::as_str).collect(),
+        (None, None) => Vec::new(),
+    }
+}
+
+impl Graph {
+    /// Emits the graph's topology as subject-predicate-object triples in
+    /// N-Triples text, so the wiring can be loaded into an external triple
+    /// store and queried independently of the crate's internal structs.
+    ///
+    /// Each `GraphNode` becomes a subject with predicates for its type, app
+    /// URI, and addon; each `GraphMessageFlow` destination becomes a typed
+    /// edge triple tagged with the message kind. Since a destination can
+    /// receive more than one differently-named flow, the flow's name(s)
+    /// (`name` or, for a multi-name flow, every entry of `names`) are
+    /// attached to a reified edge node for that specific `(kind, src, dest)`
+    /// pair rather than to the destination node itself.
+    pub fn to_triples(&self) -> String {
+        let mut lines = Vec::new();
+
+        for node in &self.nodes {
+            let subject = node_iri(node.get_name());
+            lines.push(format!(
+                "<{}> <type:kind> \"{}\" .",
+                subject,
+                escape_literal(&format!("{:?}", node.get_type()))
+            ));
+
+            if let Some(app) = node.get_app_uri() {
+                lines.push(format!("<{}> <app:uri> \"{}\" .", subject, escape_literal(app)));
+            }
+
+            if let Some(addon) = node.get_addon() {
+                lines.push(format!("<{}> <addon:name> \"{}\" .", subject, escape_literal(addon)));
+            }
+        }
+
+        if let Some(connections) = &self.connections {
+            for conn in connections {
+                let Ok(src_name) = conn.loc.get_node_name() else {
+                    continue;
+                };
+                let subject = node_iri(src_name);
+
+                for (kind, flows) in [
+                    ("cmd", &conn.cmd),
+                    ("data", &conn.data),
+                    ("audio_frame", &conn.audio_frame),
+                    ("video_frame", &conn.video_frame),
+                ] {
+                    let Some(flows) = flows else {
+                        continue;
+                    };
+
+                    for (flow_idx, flow) in flows.iter().enumerate() {
+                        let predicate = format!("flow:{}", kind);
+                        let names = flow_names(&flow.name, &flow.names);
+
+                        for dest in &flow.dest {
+                            let Ok(dest_name) = dest.loc.get_node_name() else {
+                                continue;
+                            };
+                            let object = node_iri(dest_name);
+                            lines.push(format!("<{}> <{}> <{}> .", subject, predicate, object));
+
+                            if names.is_empty() {
+                                continue;
+                            }
+
+                            let edge = edge_iri(kind, src_name, dest_name, flow_idx);
+                            lines.push(format!("<{}> <edge:from> <{}> .", edge, subject));
+                            lines.push(format!("<{}> <edge:to> <{}> .", edge, object));
+                            for name in &names {
+                                lines.push(format!(
+                                    "<{}> <flow:name> \"{}\" .",
+                                    edge,
+                                    escape_literal(name)
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        lines.join("\n")
+    }
+}
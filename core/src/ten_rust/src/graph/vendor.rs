@@ -0,0 +1,231 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+use std::{
+    collections::{BTreeMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    graph::{graph_info::GraphContent, node::GraphNode},
+    utils::{
+        integrity::IntegrityLockfile,
+        path::{get_base_dir_of_uri, get_real_path_from_import_uri},
+    },
+};
+
+/// Options controlling a single `vendor` run.
+pub struct VendorOptions {
+    /// Directory the vendored tree is written under (e.g. `vendor/`).
+    pub out_dir: PathBuf,
+
+    /// When `true`, a previously vendored file is re-fetched and overwritten.
+    /// When `false` (the default), an existing vendored file is left as-is.
+    pub force: bool,
+}
+
+/// Maps each resolved remote URL to the path (relative to `out_dir`) it was
+/// vendored at, so the original remote URLs can be re-expanded later.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct VendorManifest {
+    pub entries: BTreeMap<String, String>,
+}
+
+/// Derives a vendor-relative path for a remote URL, keyed by host+path, so
+/// directory structure is preserved and relative sub-imports inside a
+/// fetched graph continue to resolve.
+fn vendored_relative_path(resolved_url: &str) -> Result<PathBuf> {
+    let url = Url::parse(resolved_url)
+        .map_err(|e| anyhow::anyhow!("cannot vendor non-URL import '{}': {}", resolved_url, e))?;
+
+    let host = url
+        .host_str()
+        .ok_or_else(|| anyhow::anyhow!("import URL '{}' has no host to vendor under", resolved_url))?;
+
+    let mut path = PathBuf::from(host);
+    for segment in url.path().trim_start_matches('/').split('/') {
+        if !segment.is_empty() {
+            path.push(segment);
+        }
+    }
+
+    Ok(path)
+}
+
+/// Computes the relative path from the directory containing `from_file` to
+/// `to_file`, where both are themselves relative paths under the same
+/// vendor `out_dir`. Used to rewrite a vendored file's own `import_uri` so
+/// it keeps resolving however the vendored tree as a whole is relocated.
+fn relative_import_path(from_file: &Path, to_file: &Path) -> PathBuf {
+    let from_dir = from_file.parent().unwrap_or_else(|| Path::new(""));
+    let from_components: Vec<_> = from_dir.components().collect();
+    let to_components: Vec<_> = to_file.components().collect();
+
+    let common = from_components.iter().zip(to_components.iter()).take_while(|(a, b)| a == b).count();
+
+    let mut result = PathBuf::new();
+    for _ in common..from_components.len() {
+        result.push("..");
+    }
+    for component in &to_components[common..] {
+        result.push(component.as_os_str());
+    }
+    result
+}
+
+fn integrity_lockfile_path(out_dir: &Path) -> PathBuf {
+    out_dir.join("vendor.integrity.json")
+}
+
+/// Loads the integrity lockfile from a previous `vendor_graph` run, if any,
+/// so repeated vendoring of the same tree keeps checking fetched content
+/// against the digests it recorded the first time.
+fn load_integrity_lockfile(out_dir: &Path) -> Result<IntegrityLockfile> {
+    let path = integrity_lockfile_path(out_dir);
+    if !path.exists() {
+        return Ok(IntegrityLockfile::default());
+    }
+    let bytes = std::fs::read(&path)?;
+    Ok(serde_json::from_slice(&bytes)?)
+}
+
+/// Recursively walks every `import_uri` reachable from `root_import_uri`,
+/// fetching all `http(s)://` targets and writing them under `options.out_dir`
+/// keyed by host+path, rewriting each fetched file's own `import_uri`
+/// references to point at their vendored copies so the output tree is
+/// self-contained and relocatable. Returns the mapping of original resolved
+/// URL to vendored relative path.
+pub async fn vendor_graph(
+    root_import_uri: &str,
+    base_dir: Option<&str>,
+    options: &VendorOptions,
+) -> Result<VendorManifest> {
+    let mut visited = HashSet::new();
+    let mut manifest = VendorManifest::default();
+    let mut integrity = load_integrity_lockfile(&options.out_dir)?;
+
+    vendor_recursive(root_import_uri, base_dir, options, &mut visited, &mut manifest, &mut integrity).await?;
+
+    std::fs::create_dir_all(&options.out_dir)?;
+
+    let manifest_path = options.out_dir.join("vendor.lock.json");
+    std::fs::write(&manifest_path, serde_json::to_string_pretty(&manifest)?)?;
+
+    std::fs::write(integrity_lockfile_path(&options.out_dir), serde_json::to_string_pretty(&integrity)?)?;
+
+    Ok(manifest)
+}
+
+async fn fetch_bytes(url: &str) -> Result<Vec<u8>> {
+    let response = reqwest::get(url).await.map_err(|e| anyhow::anyhow!("failed to fetch '{}': {}", url, e))?;
+    let bytes =
+        response.bytes().await.map_err(|e| anyhow::anyhow!("failed to read body of '{}': {}", url, e))?;
+    Ok(bytes.to_vec())
+}
+
+/// Vendors a single `import_uri` (and, recursively, everything it imports),
+/// returning the vendored-relative path the target landed at, or `None` for
+/// a local target that was left alone.
+fn vendor_recursive<'a>(
+    import_uri: &'a str,
+    base_dir: Option<&'a str>,
+    options: &'a VendorOptions,
+    visited: &'a mut HashSet<String>,
+    manifest: &'a mut VendorManifest,
+    integrity: &'a mut IntegrityLockfile,
+) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Option<PathBuf>>> + 'a>> {
+    Box::pin(async move {
+        let resolved = get_real_path_from_import_uri(import_uri, base_dir, None)?;
+
+        if !resolved.starts_with("http://") && !resolved.starts_with("https://") {
+            // Only remote targets need to be localized.
+            return Ok(None);
+        }
+
+        let vendored_rel_path = vendored_relative_path(&resolved)?;
+
+        if !visited.insert(resolved.clone()) {
+            // Already processed this URL (dedupe the transitive closure).
+            return Ok(Some(vendored_rel_path));
+        }
+
+        let dest_path = options.out_dir.join(&vendored_rel_path);
+
+        if dest_path.exists() && !options.force {
+            // Leave a previously vendored (and already rewritten) file
+            // as-is, without re-verifying or re-walking its own imports.
+            manifest
+                .entries
+                .insert(resolved.clone(), vendored_rel_path.to_string_lossy().to_string());
+            return Ok(Some(vendored_rel_path));
+        }
+
+        let bytes = fetch_bytes(&resolved).await?;
+        integrity.verify_or_record(&resolved, &bytes)?;
+
+        // Recurse into any nested subgraph/interface imports so the output
+        // is a fully offline-buildable app tree, rewriting each one's
+        // `import_uri` in place to the vendored copy's path before writing
+        // this file to disk.
+        let nested_base_dir = get_base_dir_of_uri(&resolved)?;
+        let final_bytes = match serde_json::from_slice::<GraphContent>(&bytes) {
+            Ok(mut graph_content) => {
+                for node in &mut graph_content.nodes {
+                    if let GraphNode::Subgraph {
+                        content,
+                    } = node
+                    {
+                        let child_vendored_path = vendor_recursive(
+                            &content.graph.import_uri,
+                            Some(&nested_base_dir),
+                            options,
+                            visited,
+                            manifest,
+                            integrity,
+                        )
+                        .await?;
+
+                        if let Some(child_rel_path) = child_vendored_path {
+                            content.graph.import_uri =
+                                relative_import_path(&vendored_rel_path, &child_rel_path)
+                                    .to_string_lossy()
+                                    .to_string();
+                        }
+                    }
+                }
+                serde_json::to_vec_pretty(&graph_content)?
+            }
+            Err(_) => bytes,
+        };
+
+        if let Some(parent) = dest_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&dest_path, &final_bytes)?;
+
+        manifest
+            .entries
+            .insert(resolved.clone(), vendored_rel_path.to_string_lossy().to_string());
+
+        Ok(Some(vendored_rel_path))
+    })
+}
+
+/// Path helper exposed for callers that need to know where a given remote
+/// URL would land without actually vendoring it (e.g. for dry-run output).
+pub fn preview_vendored_path(resolved_url: &str) -> Result<PathBuf> {
+    vendored_relative_path(resolved_url)
+}
+
+/// Exposes `relative_import_path` for dry-run output, same rationale as
+/// `preview_vendored_path`.
+pub fn preview_relative_import_path(from_file: &Path, to_file: &Path) -> PathBuf {
+    relative_import_path(from_file, to_file)
+}
@@ -0,0 +1,84 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+use anyhow::Result;
+
+/// Decodes a `data:` URI (e.g. `data:application/json;base64,...` or a
+/// percent-encoded payload) into its media type and raw bytes, following
+/// monolith's `parse_data_url` handling. This lets a graph embed a small
+/// subgraph or interface definition inline instead of referencing an
+/// external file.
+pub fn parse_data_uri(uri: &str) -> Result<(String, Vec<u8>)> {
+    let rest = uri
+        .strip_prefix("data:")
+        .ok_or_else(|| anyhow::anyhow!("not a data: URI: {}", uri))?;
+
+    let (meta, payload) =
+        rest.split_once(',').ok_or_else(|| anyhow::anyhow!("malformed data: URI, missing ','"))?;
+
+    let is_base64 = meta.ends_with(";base64");
+    let media_type = meta.trim_end_matches(";base64");
+    let media_type =
+        if media_type.is_empty() { "text/plain;charset=US-ASCII".to_string() } else { media_type.to_string() };
+
+    let bytes = if is_base64 { base64_decode(payload)? } else { percent_decode(payload) };
+
+    Ok((media_type, bytes))
+}
+
+fn base64_value(byte: u8) -> Option<u8> {
+    match byte {
+        b'A'..=b'Z' => Some(byte - b'A'),
+        b'a'..=b'z' => Some(byte - b'a' + 26),
+        b'0'..=b'9' => Some(byte - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+fn base64_decode(input: &str) -> Result<Vec<u8>> {
+    let cleaned: Vec<u8> = input.bytes().filter(|b| *b != b'=' && !b.is_ascii_whitespace()).collect();
+
+    let mut out = Vec::with_capacity(cleaned.len() * 3 / 4);
+    for chunk in cleaned.chunks(4) {
+        let mut values = [0u8; 4];
+        for (i, byte) in chunk.iter().enumerate() {
+            values[i] = base64_value(*byte)
+                .ok_or_else(|| anyhow::anyhow!("invalid base64 byte '{}' in data: URI", *byte as char))?;
+        }
+
+        out.push((values[0] << 2) | (values[1] >> 4));
+        if chunk.len() > 2 {
+            out.push((values[1] << 4) | (values[2] >> 2));
+        }
+        if chunk.len() > 3 {
+            out.push((values[2] << 6) | values[3]);
+        }
+    }
+
+    Ok(out)
+}
+
+fn percent_decode(input: &str) -> Vec<u8> {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let Ok(hex) = std::str::from_utf8(&bytes[i + 1..i + 3]) {
+                if let Ok(value) = u8::from_str_radix(hex, 16) {
+                    out.push(value);
+                    i += 3;
+                    continue;
+                }
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    out
+}
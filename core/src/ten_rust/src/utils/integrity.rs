@@ -0,0 +1,54 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Computes the subresource-integrity digest (`sha256-<hex>`) of `bytes`,
+/// mirroring the format of Deno's `checksum`.
+pub fn compute_digest(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    format!("sha256-{:x}", hasher.finalize())
+}
+
+/// Records an expected content hash for every remote `import_uri` resolved
+/// during a load, keyed by the fully-resolved URL (the output of
+/// `get_real_path_from_import_uri`) so that import-map remaps and relative
+/// joins produce stable keys.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct IntegrityLockfile {
+    pub entries: BTreeMap<String, String>,
+}
+
+impl IntegrityLockfile {
+    /// Verifies `bytes` fetched for `resolved_url` against the locked
+    /// digest. If the URL is not yet locked, records the computed digest
+    /// instead of failing, so a first run bootstraps the lockfile. A
+    /// mismatch is a hard error naming the URI and both hashes, so a
+    /// remote graph's content cannot change underneath a reproducible
+    /// build.
+    pub fn verify_or_record(&mut self, resolved_url: &str, bytes: &[u8]) -> Result<()> {
+        let digest = compute_digest(bytes);
+
+        match self.entries.get(resolved_url) {
+            Some(expected) if expected != &digest => Err(anyhow::anyhow!(
+                "integrity check failed for '{}': expected '{}', got '{}'",
+                resolved_url,
+                expected,
+                digest
+            )),
+            Some(_) => Ok(()),
+            None => {
+                self.entries.insert(resolved_url.to_string(), digest);
+                Ok(())
+            }
+        }
+    }
+}
@@ -4,9 +4,13 @@
 // Licensed under the Apache License, Version 2.0, with certain conditions.
 // Refer to the "LICENSE" file in the root directory for more information.
 //
-use std::path::{Component, Path, PathBuf};
+use std::{
+    collections::HashMap,
+    path::{Component, Path, PathBuf},
+};
 
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 use url::Url;
 
 pub fn normalize_path(path: &Path) -> PathBuf {
@@ -65,6 +69,14 @@ pub fn get_base_dir_of_uri(uri: &str) -> Result<String> {
 
                 return Ok(base_url.to_string());
             }
+            "data" => {
+                // A `data:` URI has no directory structure to resolve
+                // relative imports against; a subgraph embedded this way
+                // cannot itself import relative siblings.
+                return Err(anyhow::anyhow!(
+                    "cannot compute a base directory for an inline 'data:' URI"
+                ));
+            }
             _ => {
                 #[cfg(windows)]
                 // Windows drive letter
@@ -98,6 +110,86 @@ pub fn get_base_dir_of_uri(uri: &str) -> Result<String> {
     Ok(parent_dir.to_string_lossy().to_string())
 }
 
+/// The specific failure a module-specifier resolution hit, independent of
+/// where in the import chain it happened. Mirrors Deno's `module_specifier`
+/// error design so callers can match on the kind instead of parsing a flat
+/// string.
+#[derive(Debug, Clone)]
+pub enum ModuleResolutionErrorKind {
+    InvalidUrl(String),
+    InvalidBaseUrl(String),
+    UnsupportedScheme(String),
+    AbsolutePathNotAllowed(String),
+    MissingBaseDir(String),
+    ImportNotFound(String),
+}
+
+impl std::fmt::Display for ModuleResolutionErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModuleResolutionErrorKind::InvalidUrl(uri) => write!(f, "invalid URL '{}'", uri),
+            ModuleResolutionErrorKind::InvalidBaseUrl(base) => {
+                write!(f, "invalid base URL '{}'", base)
+            }
+            ModuleResolutionErrorKind::UnsupportedScheme(scheme) => {
+                write!(f, "unsupported URL scheme '{}'", scheme)
+            }
+            ModuleResolutionErrorKind::AbsolutePathNotAllowed(uri) => write!(
+                f,
+                "absolute paths are not supported in import_uri '{}'; use a file:// URI or a \
+                 relative path instead",
+                uri
+            ),
+            ModuleResolutionErrorKind::MissingBaseDir(uri) => write!(
+                f,
+                "base_dir cannot be None when import_uri '{}' is a relative path",
+                uri
+            ),
+            ModuleResolutionErrorKind::ImportNotFound(uri) => {
+                write!(f, "'{}' not found", uri)
+            }
+        }
+    }
+}
+
+/// A resolution failure, decorated with the chain of `import_uri`s that led
+/// to it (innermost frame first), so a user sees e.g. "interface.json not
+/// found, imported from subgraph 'audio' (file:///.../audio.json), imported
+/// from root" instead of a single flat message.
+#[derive(Debug, Clone)]
+pub struct ModuleResolutionError {
+    pub kind: ModuleResolutionErrorKind,
+    pub import_chain: Vec<String>,
+}
+
+impl ModuleResolutionError {
+    pub fn new(kind: ModuleResolutionErrorKind) -> Self {
+        Self {
+            kind,
+            import_chain: Vec::new(),
+        }
+    }
+
+    /// Appends an outer frame (the subgraph/import that led to this
+    /// resolution) as the error bubbles up through recursive resolution.
+    pub fn with_chain_frame(mut self, frame: impl Into<String>) -> Self {
+        self.import_chain.push(frame.into());
+        self
+    }
+}
+
+impl std::fmt::Display for ModuleResolutionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.kind)?;
+        for frame in &self.import_chain {
+            write!(f, ", imported from {}", frame)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ModuleResolutionError {}
+
 /// Get the real path of the import_uri based on the base_dir.
 ///
 /// The import_uri can be a relative path or a URL.
@@ -105,6 +197,13 @@ pub fn get_base_dir_of_uri(uri: &str) -> Result<String> {
 /// path.
 /// If import_uri contains ${app_base_dir}, it will be replaced with the
 /// app_base_dir parameter.
+/// A `data:` URI is returned unchanged rather than joined against `base_dir`,
+/// on the expectation that a caller which wants the inline payload decodes
+/// it itself via [`crate::utils::data_uri::parse_data_uri`] instead of
+/// reading a file or making a network request. As of this writing,
+/// `parse_data_uri` has no call site anywhere in this crate wired up to
+/// actually do that -- resolving a graph via a `data:` `import_uri` will
+/// return the URI string itself rather than the decoded graph content.
 pub fn get_real_path_from_import_uri(
     import_uri: &str,
     raw_base_dir: Option<&str>,
@@ -113,11 +212,10 @@ pub fn get_real_path_from_import_uri(
     // If the import_uri is an absolute path (without variable substitution),
     // return an error because absolute paths should use file:// URI
     if Path::new(import_uri).is_absolute() && !import_uri.contains("${app_base_dir}") {
-        return Err(anyhow::anyhow!(
-            "Absolute paths are not supported in import_uri: {}. Use file:// URI or relative path \
-             instead",
-            import_uri
-        ));
+        return Err(ModuleResolutionError::new(ModuleResolutionErrorKind::AbsolutePathNotAllowed(
+            import_uri.to_string(),
+        ))
+        .into());
     }
 
     // Sanitize path (only on Windows).
@@ -154,11 +252,10 @@ pub fn get_real_path_from_import_uri(
             // Replace ${app_base_dir} with the actual app base directory
             import_uri.replace("${app_base_dir}", app_base_dir)
         } else {
-            return Err(anyhow::anyhow!(
-                "app_base_dir must be provided when import_uri contains ${{app_base_dir}} \
-                 variable: {}",
-                import_uri
-            ));
+            return Err(ModuleResolutionError::new(ModuleResolutionErrorKind::MissingBaseDir(
+                import_uri.to_string(),
+            ))
+            .into());
         }
     } else {
         import_uri.to_string()
@@ -188,6 +285,12 @@ pub fn get_real_path_from_import_uri(
             "file" => {
                 return Ok(url.to_string());
             }
+            "data" => {
+                // A `data:` URI is self-contained (the payload is embedded
+                // in the URI itself), so it resolves to itself rather than
+                // being joined against a base_dir.
+                return Ok(processed_import_uri);
+            }
             _ => {
                 // Windows drive letter - Check if it's a single character and alphabetic
                 if url.scheme().len() == 1
@@ -198,12 +301,10 @@ pub fn get_real_path_from_import_uri(
                     // Continue to parse the processed_import_uri as a file
                     // path.
                 } else {
-                    return Err(anyhow::anyhow!(
-                        "Unsupported URL scheme '{}' in import_uri: {} when \
-                         get_real_path_from_import_uri",
-                        url.scheme(),
-                        processed_import_uri
-                    ));
+                    return Err(ModuleResolutionError::new(ModuleResolutionErrorKind::UnsupportedScheme(
+                        url.scheme().to_string(),
+                    ))
+                    .into());
                 }
             }
         }
@@ -213,10 +314,10 @@ pub fn get_real_path_from_import_uri(
 
     // If the base_dir is not provided, return an error.
     if base_dir.is_none() || base_dir.unwrap().is_empty() {
-        return Err(anyhow::anyhow!(
-            "base_dir cannot be None when uri is a relative path, import_uri: \
-             {processed_import_uri}"
-        ));
+        return Err(ModuleResolutionError::new(ModuleResolutionErrorKind::MissingBaseDir(
+            processed_import_uri.clone(),
+        ))
+        .into());
     }
 
     // If the base_dir is a URL, calculate the real path based on the URL.
@@ -242,13 +343,11 @@ pub fn get_real_path_from_import_uri(
 
                     return Ok(resolved_url.to_string());
                 }
-                Err(e) => {
-                    return Err(anyhow::anyhow!(
-                        "Failed to resolve relative path '{}' against base URL '{}': {}",
-                        processed_import_uri,
-                        base_dir.unwrap(),
-                        e
-                    ));
+                Err(_) => {
+                    return Err(ModuleResolutionError::new(ModuleResolutionErrorKind::InvalidBaseUrl(
+                        base_dir.unwrap().to_string(),
+                    ))
+                    .into());
                 }
             }
         }
@@ -260,3 +359,159 @@ pub fn get_real_path_from_import_uri(
     // Normalize the path to resolve '.' and '..' components
     Ok(normalize_path(&path).to_string_lossy().to_string())
 }
+
+/// An import-map document that `get_real_path_from_import_uri` can consult
+/// before resolving, modeled on Deno's `import_map`.
+///
+/// `imports` maps an exact specifier (or, with a trailing slash, a prefix)
+/// to a target. `scopes` maps a base-dir prefix to its own `imports` table;
+/// the most specific matching scope wins over the top-level `imports`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ImportMap {
+    #[serde(default)]
+    pub imports: HashMap<String, String>,
+
+    #[serde(default)]
+    pub scopes: HashMap<String, HashMap<String, String>>,
+}
+
+/// Rewrites `specifier` using a single imports table, if it matches either
+/// an exact key or a trailing-slash prefix key. Returns `None` when nothing
+/// in `imports` applies.
+fn remap_with_imports(specifier: &str, imports: &HashMap<String, String>) -> Option<String> {
+    if let Some(target) = imports.get(specifier) {
+        return Some(target.clone());
+    }
+
+    // Trailing-slash keys act as prefix remaps: the longest matching prefix
+    // wins so that a more specific remap takes priority.
+    imports
+        .iter()
+        .filter(|(key, _)| key.ends_with('/') && specifier.starts_with(key.as_str()))
+        .max_by_key(|(key, _)| key.len())
+        .map(|(key, target)| format!("{}{}", target, &specifier[key.len()..]))
+}
+
+/// Rewrites `import_uri` using `map`, preferring the most specific scope
+/// whose base-dir prefix matches `base_dir` over the top-level `imports`
+/// table. Returns the original `import_uri` unchanged when nothing in the
+/// map applies.
+fn apply_import_map(import_uri: &str, base_dir: Option<&str>, map: &ImportMap) -> String {
+    if let Some(base_dir) = base_dir {
+        let mut matching_scopes: Vec<&String> =
+            map.scopes.keys().filter(|prefix| base_dir.starts_with(prefix.as_str())).collect();
+        // The most specific (longest) scope prefix wins.
+        matching_scopes.sort_by_key(|prefix| std::cmp::Reverse(prefix.len()));
+
+        for scope_prefix in matching_scopes {
+            if let Some(imports) = map.scopes.get(scope_prefix) {
+                if let Some(remapped) = remap_with_imports(import_uri, imports) {
+                    return remapped;
+                }
+            }
+        }
+    }
+
+    remap_with_imports(import_uri, &map.imports).unwrap_or_else(|| import_uri.to_string())
+}
+
+/// Gates which schemes and hosts an `import_uri` may resolve to, borrowing
+/// Deno's `--allow-import`. `file://` (and relative/local paths, which
+/// resolve to a local path rather than a URL) are always permitted; `http`/
+/// `https` targets are denied by default unless their `host:port` is
+/// explicitly present in `allowed_hosts`.
+#[derive(Debug, Clone, Default)]
+pub struct ImportPolicy {
+    allowed_hosts: std::collections::HashSet<String>,
+}
+
+impl ImportPolicy {
+    /// Creates a policy that denies every `http`/`https` host.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a `host:port` entry (e.g. `"example.com:443"`) to the allowlist.
+    pub fn allow_host(&mut self, host_port: impl Into<String>) {
+        self.allowed_hosts.insert(host_port.into());
+    }
+
+    /// Checks a fully-resolved URL (the output of
+    /// `get_real_path_from_import_uri`) against this policy.
+    pub fn check(&self, resolved_url: &str) -> Result<()> {
+        let Ok(url) = Url::parse(resolved_url) else {
+            // Not a URL, i.e. a local filesystem path: always permitted.
+            return Ok(());
+        };
+
+        match url.scheme() {
+            "http" | "https" => {
+                let port = url.port_or_known_default().unwrap_or(0);
+                let host = url.host_str().unwrap_or("");
+                let host_port = format!("{}:{}", host, port);
+
+                if self.allowed_hosts.contains(&host_port) {
+                    Ok(())
+                } else {
+                    Err(anyhow::anyhow!("import from '{}' is not permitted", host_port))
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Resolves `import_uri` and enforces `policy` against the resolved URL,
+/// preventing a malicious or typo'd subgraph from silently pulling
+/// executable graph definitions off an untrusted server during validation.
+pub fn get_real_path_from_import_uri_with_policy(
+    import_uri: &str,
+    base_dir: Option<&str>,
+    app_base_dir: Option<&str>,
+    policy: &ImportPolicy,
+) -> Result<String> {
+    let resolved = get_real_path_from_import_uri(import_uri, base_dir, app_base_dir)?;
+    policy.check(&resolved)?;
+    Ok(resolved)
+}
+
+/// Resolves `import_uri` the same way `get_real_path_from_import_uri` does,
+/// but first consults `map` so graph authors can alias `${app_base_dir}`
+/// targets, pin a remote subgraph host to a local mirror, or otherwise share
+/// one mapping across many graphs without editing every `import_uri`.
+pub fn resolve_with_import_map(
+    import_uri: &str,
+    base_dir: Option<&str>,
+    app_base_dir: Option<&str>,
+    map: &ImportMap,
+) -> Result<String> {
+    let remapped = apply_import_map(import_uri, base_dir, map);
+    get_real_path_from_import_uri(&remapped, base_dir, app_base_dir)
+}
+
+/// Resolves `import_uri` for the subgraph loaders: remaps it through
+/// `import_map` when one is given, then enforces `policy` against the
+/// resolved URL when one is given. Both layers are no-ops when their
+/// corresponding argument is `None`, so callers that don't have an import
+/// map or a policy in scope (e.g. `GraphContent::write_lock`) resolve
+/// exactly as `get_real_path_from_import_uri` always has.
+pub fn resolve_import_uri(
+    import_uri: &str,
+    base_dir: Option<&str>,
+    app_base_dir: Option<&str>,
+    import_map: Option<&ImportMap>,
+    policy: Option<&ImportPolicy>,
+) -> Result<String> {
+    let remapped = match import_map {
+        Some(map) => apply_import_map(import_uri, base_dir, map),
+        None => import_uri.to_string(),
+    };
+
+    let resolved = get_real_path_from_import_uri(&remapped, base_dir, app_base_dir)?;
+
+    if let Some(policy) = policy {
+        policy.check(&resolved)?;
+    }
+
+    Ok(resolved)
+}
@@ -0,0 +1,240 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+#[cfg(test)]
+mod tests {
+    use ten_rust::graph::{
+        connection::{GraphConnection, GraphDestination, GraphLoc, GraphMessageFlow},
+        node::GraphNode,
+        Graph,
+    };
+
+    #[test]
+    fn test_to_dot_emits_node_and_edge() {
+        let graph = Graph {
+            nodes: vec![
+                GraphNode::new_extension_node(
+                    "ext_a".to_string(),
+                    "ext_a_addon".to_string(),
+                    Some("some_group".to_string()),
+                    None,
+                    None,
+                ),
+                GraphNode::new_extension_node(
+                    "ext_b".to_string(),
+                    "ext_b_addon".to_string(),
+                    Some("some_group".to_string()),
+                    None,
+                    None,
+                ),
+            ],
+            connections: Some(vec![GraphConnection {
+                loc: GraphLoc {
+                    extension: Some("ext_a".to_string()),
+                    app: None,
+                    subgraph: None,
+                    selector: None,
+                },
+                cmd: Some(vec![GraphMessageFlow::new(
+                    Some("hello".to_string()),
+                    None,
+                    vec![GraphDestination {
+                        loc: GraphLoc {
+                            extension: Some("ext_b".to_string()),
+                            app: None,
+                            subgraph: None,
+                            selector: None,
+                        },
+                        msg_conversion: None,
+                    }],
+                    vec![],
+                )]),
+                data: None,
+                audio_frame: None,
+                video_frame: None,
+            }]),
+            exposed_messages: None,
+            exposed_properties: None,
+        };
+
+        let dot = graph.to_dot();
+
+        assert!(dot.starts_with("digraph G {"));
+        assert!(dot.contains("\"ext_a\""));
+        assert!(dot.contains("\"ext_b\""));
+        assert!(dot.contains("\"ext_a\" -> \"ext_b\""));
+        assert!(dot.contains("cmd: hello"));
+        assert!(dot.contains("color=black"));
+    }
+
+    #[test]
+    fn test_to_dot_gives_graph_proxy_node_a_distinct_shape() {
+        let graph = Graph {
+            nodes: vec![GraphNode::new_extension_node(
+                "ten:graph_proxy".to_string(),
+                "graph_proxy_addon".to_string(),
+                None,
+                None,
+                None,
+            )],
+            connections: None,
+            exposed_messages: None,
+            exposed_properties: None,
+        };
+
+        let dot = graph.to_dot();
+
+        assert!(dot.contains("\"ten:graph_proxy\" [label=\"ten:graph_proxy"));
+        assert!(dot.contains("shape=box3d"));
+    }
+
+    #[test]
+    fn test_to_dot_colors_edges_by_message_class() {
+        let graph = Graph {
+            nodes: vec![
+                GraphNode::new_extension_node("ext_a".to_string(), "addon".to_string(), None, None, None),
+                GraphNode::new_extension_node("ext_b".to_string(), "addon".to_string(), None, None, None),
+            ],
+            connections: Some(vec![GraphConnection {
+                loc: GraphLoc {
+                    extension: Some("ext_a".to_string()),
+                    app: None,
+                    subgraph: None,
+                    selector: None,
+                },
+                cmd: None,
+                data: Some(vec![GraphMessageFlow::new(
+                    Some("payload".to_string()),
+                    None,
+                    vec![GraphDestination {
+                        loc: GraphLoc {
+                            extension: Some("ext_b".to_string()),
+                            app: None,
+                            subgraph: None,
+                            selector: None,
+                        },
+                        msg_conversion: None,
+                    }],
+                    vec![],
+                )]),
+                audio_frame: None,
+                video_frame: None,
+            }]),
+            exposed_messages: None,
+            exposed_properties: None,
+        };
+
+        let dot = graph.to_dot();
+
+        assert!(dot.contains("color=blue"));
+        assert!(dot.contains("style=solid"));
+    }
+
+    #[test]
+    fn test_to_dot_clusters_nodes_by_app() {
+        let graph = Graph {
+            nodes: vec![GraphNode::new_extension_node(
+                "ext_a".to_string(),
+                "ext_a_addon".to_string(),
+                Some("some_group".to_string()),
+                None,
+                None,
+            )],
+            connections: Some(vec![GraphConnection {
+                loc: GraphLoc {
+                    extension: Some("ext_a".to_string()),
+                    app: None,
+                    subgraph: None,
+                    selector: None,
+                },
+                cmd: Some(vec![GraphMessageFlow::new(
+                    Some("hello".to_string()),
+                    None,
+                    vec![GraphDestination {
+                        loc: GraphLoc {
+                            extension: Some("ext_c".to_string()),
+                            app: Some("http://remote-app:8000".to_string()),
+                            subgraph: None,
+                            selector: None,
+                        },
+                        msg_conversion: None,
+                    }],
+                    vec![],
+                )]),
+                data: None,
+                audio_frame: None,
+                video_frame: None,
+            }]),
+            exposed_messages: None,
+            exposed_properties: None,
+        };
+
+        let dot = graph.to_dot();
+
+        assert!(dot.contains("subgraph \"cluster_http://remote-app:8000\""));
+        assert!(dot.contains("\"http://remote-app:8000::ext_c\""));
+    }
+
+    #[test]
+    fn test_to_dot_qualifies_declared_node_with_its_own_app() {
+        let graph = Graph {
+            nodes: vec![GraphNode::new_extension_node(
+                "ext_remote".to_string(),
+                "ext_remote_addon".to_string(),
+                None,
+                Some("http://remote-app:8000".to_string()),
+                None,
+            )],
+            connections: Some(vec![GraphConnection {
+                loc: GraphLoc {
+                    extension: Some("ext_remote".to_string()),
+                    app: Some("http://remote-app:8000".to_string()),
+                    subgraph: None,
+                    selector: None,
+                },
+                cmd: Some(vec![GraphMessageFlow::new(
+                    Some("hello".to_string()),
+                    None,
+                    vec![GraphDestination {
+                        loc: GraphLoc {
+                            extension: Some("ext_local".to_string()),
+                            app: None,
+                            subgraph: None,
+                            selector: None,
+                        },
+                        msg_conversion: None,
+                    }],
+                    vec![],
+                )]),
+                data: None,
+                audio_frame: None,
+                video_frame: None,
+            }]),
+            exposed_messages: None,
+            exposed_properties: None,
+        };
+
+        let dot = graph.to_dot();
+
+        // The declared node's own id must match the app-qualified id the
+        // edge loop uses for the same location, or it renders as a second,
+        // disconnected box instead of the endpoint of the "-> ext_local" edge.
+        assert!(dot.contains("\"http://remote-app:8000::ext_remote\" -> \"ext_local\""));
+        assert!(dot.contains("subgraph \"cluster_http://remote-app:8000\""));
+        assert!(dot.contains("\"http://remote-app:8000::ext_remote\" [label="));
+        assert!(!dot.contains("\"ext_remote\" [label="));
+
+        // `ext_remote` is declared once via `self.nodes` (label
+        // "ext_remote (Extension)") and is then the same loc `conn.loc`
+        // resolves to; it must not be declared a second time with the
+        // inferior plain-name label, which would silently clobber the
+        // first declaration's label in Graphviz's rendering.
+        let declaration_count =
+            dot.matches("\"http://remote-app:8000::ext_remote\" [label=").count();
+        assert_eq!(declaration_count, 1);
+        assert!(dot.contains("\"http://remote-app:8000::ext_remote\" [label=\"ext_remote (Extension)\"]"));
+    }
+}
@@ -9,6 +9,7 @@ mod tests {
     use ten_rust::graph::{
         connection::{GraphConnection, GraphDestination, GraphLoc, GraphMessageFlow},
         node::GraphNode,
+        transport::GraphProxyTransportKind,
         Graph, GraphExposedMessage, GraphExposedMessageType,
     };
 
@@ -366,6 +367,156 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_negotiate_graph_proxy_transport_picks_first_supported() {
+        // Create a graph with exposed message.
+        let graph = Graph {
+            nodes: vec![GraphNode::new_extension_node(
+                "function_entry".to_string(),
+                "function_entry_addon".to_string(),
+                Some("some_group".to_string()),
+                None,
+                None,
+            )],
+            connections: None,
+            exposed_messages: Some(vec![GraphExposedMessage {
+                msg_type: GraphExposedMessageType::CmdIn,
+                name: "function_call".to_string(),
+                extension: Some("function_entry".to_string()),
+                subgraph: None,
+            }]),
+            exposed_properties: None,
+        };
+
+        // The remote app offers websocket first, then tcp.
+        let host_loc = serde_json::json!({
+            "app": "http://localhost:8000",
+            "graph": "parent_graph",
+            "extension": "caller_extension",
+            "transports": ["websocket", "tcp"]
+        });
+
+        let mut new_graph = graph
+            .inject_graph_proxy_from_exposed_messages(Some(&host_loc.to_string()))
+            .unwrap()
+            .unwrap();
+
+        // This runtime only supports tcp and in_process, so tcp should win
+        // even though websocket was offered first.
+        new_graph
+            .negotiate_graph_proxy_transport(&[GraphProxyTransportKind::Tcp, GraphProxyTransportKind::InProcess])
+            .unwrap();
+
+        let proxy_node = new_graph
+            .nodes
+            .iter()
+            .find(|node| node.get_name() == "ten:graph_proxy")
+            .expect("ten:graph_proxy node should exist");
+
+        if let GraphNode::Extension {
+            content,
+        } = proxy_node
+        {
+            let property = content.property.as_ref().unwrap();
+            let host_loc = &property["host_loc"];
+            assert_eq!(host_loc["transport"].as_str().unwrap(), "tcp");
+            assert_eq!(
+                host_loc["channels"].as_array().unwrap().iter().map(|v| v.as_str().unwrap()).collect::<Vec<_>>(),
+                vec!["cmd", "data", "audio_frame", "video_frame"]
+            );
+
+            // Round-trip the recorded negotiation back through serde.
+            let transport: GraphProxyTransportKind = serde_json::from_value(host_loc["transport"].clone()).unwrap();
+            assert_eq!(transport, GraphProxyTransportKind::Tcp);
+        } else {
+            panic!("ten:graph_proxy should be an extension node");
+        }
+    }
+
+    #[test]
+    fn test_negotiate_graph_proxy_transport_errors_when_no_match() {
+        let graph = Graph {
+            nodes: vec![GraphNode::new_extension_node(
+                "function_entry".to_string(),
+                "function_entry_addon".to_string(),
+                Some("some_group".to_string()),
+                None,
+                None,
+            )],
+            connections: None,
+            exposed_messages: Some(vec![GraphExposedMessage {
+                msg_type: GraphExposedMessageType::CmdIn,
+                name: "function_call".to_string(),
+                extension: Some("function_entry".to_string()),
+                subgraph: None,
+            }]),
+            exposed_properties: None,
+        };
+
+        let host_loc = serde_json::json!({
+            "app": "http://localhost:8000",
+            "graph": "parent_graph",
+            "extension": "caller_extension",
+            "transports": ["websocket"]
+        });
+
+        let mut new_graph = graph
+            .inject_graph_proxy_from_exposed_messages(Some(&host_loc.to_string()))
+            .unwrap()
+            .unwrap();
+
+        let err =
+            new_graph.negotiate_graph_proxy_transport(&[GraphProxyTransportKind::Tcp]).unwrap_err();
+        assert!(err.to_string().contains("none of host_loc.transports"));
+    }
+
+    #[test]
+    fn test_negotiate_graph_proxy_transport_no_op_without_transports() {
+        // Mirrors test_inject_graph_proxy_with_host_loc_property's host_loc,
+        // which declares no `transports` list.
+        let graph = Graph {
+            nodes: vec![GraphNode::new_extension_node(
+                "function_entry".to_string(),
+                "function_entry_addon".to_string(),
+                Some("some_group".to_string()),
+                None,
+                None,
+            )],
+            connections: None,
+            exposed_messages: Some(vec![GraphExposedMessage {
+                msg_type: GraphExposedMessageType::CmdIn,
+                name: "function_call".to_string(),
+                extension: Some("function_entry".to_string()),
+                subgraph: None,
+            }]),
+            exposed_properties: None,
+        };
+
+        let host_loc = serde_json::json!({
+            "app": "http://localhost:8000",
+            "graph": "parent_graph",
+            "extension": "caller_extension"
+        });
+
+        let mut new_graph = graph
+            .inject_graph_proxy_from_exposed_messages(Some(&host_loc.to_string()))
+            .unwrap()
+            .unwrap();
+
+        new_graph.negotiate_graph_proxy_transport(&[GraphProxyTransportKind::WebSocket]).unwrap();
+
+        let proxy_node = new_graph.nodes.iter().find(|node| node.get_name() == "ten:graph_proxy").unwrap();
+        if let GraphNode::Extension {
+            content,
+        } = proxy_node
+        {
+            let property = content.property.as_ref().unwrap();
+            assert!(property["host_loc"].get("transport").is_none());
+        } else {
+            panic!("ten:graph_proxy should be an extension node");
+        }
+    }
+
     #[test]
     fn test_inject_graph_proxy_with_no_exposed_messages() {
         // Create a graph without exposed messages.
@@ -0,0 +1,68 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+#[cfg(test)]
+mod tests {
+    use ten_rust::graph::lock::{GraphLockEntry, GraphLockfile};
+
+    #[test]
+    fn test_lock_diff_detects_added_removed_and_changed() {
+        let mut before = GraphLockfile::default();
+        before.entries.insert(
+            "selector_for_ext_1_and_2".to_string(),
+            GraphLockEntry {
+                members: vec!["test_extension_1".to_string(), "test_extension_2".to_string()],
+                content_hash: "aaaa".to_string(),
+            },
+        );
+        before.entries.insert(
+            "selector_to_be_removed".to_string(),
+            GraphLockEntry {
+                members: vec!["test_extension_9".to_string()],
+                content_hash: "bbbb".to_string(),
+            },
+        );
+
+        let mut after = GraphLockfile::default();
+        // Reordered members -> counts as changed.
+        after.entries.insert(
+            "selector_for_ext_1_and_2".to_string(),
+            GraphLockEntry {
+                members: vec!["test_extension_2".to_string(), "test_extension_1".to_string()],
+                content_hash: "cccc".to_string(),
+            },
+        );
+        after.entries.insert(
+            "selector_newly_added".to_string(),
+            GraphLockEntry {
+                members: vec!["test_extension_5".to_string()],
+                content_hash: "dddd".to_string(),
+            },
+        );
+
+        let diff = before.diff(&after);
+
+        assert_eq!(diff.added, vec!["selector_newly_added".to_string()]);
+        assert_eq!(diff.removed, vec!["selector_to_be_removed".to_string()]);
+        assert_eq!(diff.changed, vec!["selector_for_ext_1_and_2".to_string()]);
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn test_lock_diff_empty_when_unchanged() {
+        let mut lock = GraphLockfile::default();
+        lock.entries.insert(
+            "selector_for_ext_1_and_2".to_string(),
+            GraphLockEntry {
+                members: vec!["test_extension_1".to_string(), "test_extension_2".to_string()],
+                content_hash: "aaaa".to_string(),
+            },
+        );
+
+        let diff = lock.diff(&lock.clone());
+        assert!(diff.is_empty());
+    }
+}
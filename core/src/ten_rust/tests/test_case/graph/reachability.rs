@@ -0,0 +1,209 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+#[cfg(test)]
+mod tests {
+    use ten_rust::graph::{
+        connection::{GraphConnection, GraphDestination, GraphLoc, GraphMessageFlow},
+        node::GraphNode,
+        Graph, GraphExposedMessage, GraphExposedMessageType,
+    };
+
+    fn loc(extension: &str) -> GraphLoc {
+        GraphLoc {
+            extension: Some(extension.to_string()),
+            app: None,
+            subgraph: None,
+            selector: None,
+        }
+    }
+
+    #[test]
+    fn test_analyze_reachability_finds_dead_extension() {
+        // ext_a --cmd--> ext_b, ext_c is never a destination and never a
+        // source, so it is unreachable.
+        let graph = Graph {
+            nodes: vec![
+                GraphNode::new_extension_node("ext_a".to_string(), "addon_a".to_string(), None, None, None),
+                GraphNode::new_extension_node("ext_b".to_string(), "addon_b".to_string(), None, None, None),
+                GraphNode::new_extension_node("ext_c".to_string(), "addon_c".to_string(), None, None, None),
+            ],
+            connections: Some(vec![GraphConnection {
+                loc: loc("ext_a"),
+                cmd: Some(vec![GraphMessageFlow::new(
+                    Some("hello".to_string()),
+                    None,
+                    vec![GraphDestination {
+                        loc: loc("ext_b"),
+                        msg_conversion: None,
+                    }],
+                    vec![],
+                )]),
+                data: None,
+                audio_frame: None,
+                video_frame: None,
+            }]),
+            exposed_messages: None,
+            exposed_properties: None,
+        };
+
+        let report = graph.analyze_reachability();
+        assert_eq!(report.unreachable_nodes, vec!["ext_c".to_string()]);
+        assert!(report.orphan_flows.is_empty());
+    }
+
+    #[test]
+    fn test_analyze_reachability_finds_orphan_flow() {
+        // ext_a --cmd--> ext_a (self loop, never fed from outside) is an
+        // orphan flow.
+        let graph = Graph {
+            nodes: vec![GraphNode::new_extension_node(
+                "ext_a".to_string(),
+                "addon_a".to_string(),
+                None,
+                None,
+                None,
+            )],
+            connections: Some(vec![GraphConnection {
+                loc: loc("ext_a"),
+                cmd: Some(vec![GraphMessageFlow::new(
+                    Some("hello".to_string()),
+                    None,
+                    vec![GraphDestination {
+                        loc: loc("ext_a"),
+                        msg_conversion: None,
+                    }],
+                    vec![],
+                )]),
+                data: None,
+                audio_frame: None,
+                video_frame: None,
+            }]),
+            exposed_messages: None,
+            exposed_properties: None,
+        };
+
+        let report = graph.analyze_reachability();
+        assert_eq!(report.orphan_flows, vec![0]);
+    }
+
+    #[test]
+    fn test_analyze_reachability_handles_cycle() {
+        // ext_a --cmd--> ext_b --cmd--> ext_a. Since neither is ever a
+        // declared entry point (each also appears as a destination), both
+        // should be flagged unreachable, and the fixpoint must still
+        // terminate.
+        let graph = Graph {
+            nodes: vec![
+                GraphNode::new_extension_node("ext_a".to_string(), "addon_a".to_string(), None, None, None),
+                GraphNode::new_extension_node("ext_b".to_string(), "addon_b".to_string(), None, None, None),
+            ],
+            connections: Some(vec![
+                GraphConnection {
+                    loc: loc("ext_a"),
+                    cmd: Some(vec![GraphMessageFlow::new(
+                        Some("ping".to_string()),
+                        None,
+                        vec![GraphDestination {
+                            loc: loc("ext_b"),
+                            msg_conversion: None,
+                        }],
+                        vec![],
+                    )]),
+                    data: None,
+                    audio_frame: None,
+                    video_frame: None,
+                },
+                GraphConnection {
+                    loc: loc("ext_b"),
+                    cmd: Some(vec![GraphMessageFlow::new(
+                        Some("pong".to_string()),
+                        None,
+                        vec![GraphDestination {
+                            loc: loc("ext_a"),
+                            msg_conversion: None,
+                        }],
+                        vec![],
+                    )]),
+                    data: None,
+                    audio_frame: None,
+                    video_frame: None,
+                },
+            ]),
+            exposed_messages: None,
+            exposed_properties: None,
+        };
+
+        let report = graph.analyze_reachability();
+        let mut unreachable = report.unreachable_nodes.clone();
+        unreachable.sort();
+        assert_eq!(unreachable, vec!["ext_a".to_string(), "ext_b".to_string()]);
+    }
+
+    #[test]
+    fn test_analyze_reachability_treats_exposed_in_as_root() {
+        // ext_a is never a declared source and never a destination, but it
+        // is named by an exposed_messages `CmdIn` entry, so it should count
+        // as reachable (an external caller can invoke it directly).
+        let graph = Graph {
+            nodes: vec![GraphNode::new_extension_node(
+                "ext_a".to_string(),
+                "addon_a".to_string(),
+                None,
+                None,
+                None,
+            )],
+            connections: None,
+            exposed_messages: Some(vec![GraphExposedMessage {
+                msg_type: GraphExposedMessageType::CmdIn,
+                name: "hello".to_string(),
+                extension: Some("ext_a".to_string()),
+                subgraph: None,
+            }]),
+            exposed_properties: None,
+        };
+
+        let report = graph.analyze_reachability();
+        assert!(report.unreachable_nodes.is_empty());
+    }
+
+    #[test]
+    fn test_analyze_reachability_finds_dead_output_node() {
+        // ext_a --cmd--> ext_b, and only ext_a is exposed as a `CmdOut`
+        // root, so ext_b's output can never escape the graph.
+        let graph = Graph {
+            nodes: vec![
+                GraphNode::new_extension_node("ext_a".to_string(), "addon_a".to_string(), None, None, None),
+                GraphNode::new_extension_node("ext_b".to_string(), "addon_b".to_string(), None, None, None),
+            ],
+            connections: Some(vec![GraphConnection {
+                loc: loc("ext_a"),
+                cmd: Some(vec![GraphMessageFlow::new(
+                    Some("hello".to_string()),
+                    None,
+                    vec![GraphDestination {
+                        loc: loc("ext_b"),
+                        msg_conversion: None,
+                    }],
+                    vec![],
+                )]),
+                data: None,
+                audio_frame: None,
+                video_frame: None,
+            }]),
+            exposed_messages: Some(vec![GraphExposedMessage {
+                msg_type: GraphExposedMessageType::CmdOut,
+                name: "hello".to_string(),
+                extension: Some("ext_a".to_string()),
+                subgraph: None,
+            }]),
+            exposed_properties: None,
+        };
+
+        let report = graph.analyze_reachability();
+        assert_eq!(report.dead_output_nodes, vec!["ext_b".to_string()]);
+    }
+}
@@ -0,0 +1,118 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+#[cfg(test)]
+mod tests {
+    use ten_rust::graph::{
+        node::GraphNode,
+        selector_expr::{SelectorExpr, SelectorPredicate},
+        Graph,
+    };
+
+    fn sample_graph() -> Graph {
+        Graph {
+            nodes: vec![
+                GraphNode::new_extension_node(
+                    "ext_1".to_string(),
+                    "addon_a".to_string(),
+                    None,
+                    Some("http://app-x".to_string()),
+                    None,
+                ),
+                GraphNode::new_extension_node(
+                    "ext_2".to_string(),
+                    "addon_b".to_string(),
+                    None,
+                    Some("http://app-x".to_string()),
+                    None,
+                ),
+                GraphNode::new_extension_node(
+                    "ext_3".to_string(),
+                    "addon_a".to_string(),
+                    None,
+                    Some("http://app-y".to_string()),
+                    None,
+                ),
+            ],
+            connections: None,
+            exposed_messages: None,
+            exposed_properties: None,
+        }
+    }
+
+    #[test]
+    fn test_any_of_is_union() {
+        let graph = sample_graph();
+        let expr = SelectorExpr::AnyOf {
+            any_of: vec![
+                SelectorExpr::Predicate(SelectorPredicate {
+                    addon: Some("addon_a".to_string()),
+                    app: None,
+                }),
+                SelectorExpr::Predicate(SelectorPredicate {
+                    addon: Some("addon_b".to_string()),
+                    app: None,
+                }),
+            ],
+        };
+
+        let matches = graph.evaluate_selector_expr(&expr);
+        let names: Vec<&str> = matches.iter().map(|n| n.get_name()).collect();
+        assert_eq!(names, vec!["ext_1", "ext_2", "ext_3"]);
+    }
+
+    #[test]
+    fn test_all_of_is_intersection() {
+        let graph = sample_graph();
+        let expr = SelectorExpr::AllOf {
+            all_of: vec![
+                SelectorExpr::Predicate(SelectorPredicate {
+                    addon: Some("addon_a".to_string()),
+                    app: None,
+                }),
+                SelectorExpr::Predicate(SelectorPredicate {
+                    addon: None,
+                    app: Some("http://app-x".to_string()),
+                }),
+            ],
+        };
+
+        let matches = graph.evaluate_selector_expr(&expr);
+        let names: Vec<&str> = matches.iter().map(|n| n.get_name()).collect();
+        assert_eq!(names, vec!["ext_1"]);
+    }
+
+    #[test]
+    fn test_not_is_difference() {
+        let graph = sample_graph();
+        let expr = SelectorExpr::Not {
+            not: Box::new(SelectorExpr::Predicate(SelectorPredicate {
+                addon: None,
+                app: Some("http://app-x".to_string()),
+            })),
+        };
+
+        let matches = graph.evaluate_selector_expr(&expr);
+        let names: Vec<&str> = matches.iter().map(|n| n.get_name()).collect();
+        assert_eq!(names, vec!["ext_3"]);
+    }
+
+    #[test]
+    fn test_selector_expr_parses_from_json() {
+        let json = serde_json::json!({
+            "all_of": [
+                { "addon": "addon_a" },
+                { "not": { "app": "http://app-y" } }
+            ]
+        });
+
+        let expr: SelectorExpr = serde_json::from_value(json).unwrap();
+        let graph = sample_graph();
+        let matches = graph.evaluate_selector_expr(&expr);
+        let names: Vec<&str> = matches.iter().map(|n| n.get_name()).collect();
+        assert_eq!(names, vec!["ext_1"]);
+    }
+}
@@ -0,0 +1,159 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+#[cfg(test)]
+mod tests {
+    use ten_rust::graph::{
+        connection::{GraphConnection, GraphDestination, GraphLoc, GraphMessageFlow, GraphSource},
+        node::GraphNode,
+        selector_expr::{SelectorExpr, SelectorPredicate},
+        Graph,
+    };
+
+    fn sinks_selector_graph() -> Graph {
+        Graph {
+            nodes: vec![
+                GraphNode::new_extension_node(
+                    "tts".to_string(),
+                    "tts_addon".to_string(),
+                    None,
+                    None,
+                    None,
+                ),
+                GraphNode::new_extension_node(
+                    "logger".to_string(),
+                    "logger_addon".to_string(),
+                    Some("sinks".to_string()),
+                    None,
+                    None,
+                ),
+                GraphNode::new_extension_node(
+                    "recorder".to_string(),
+                    "recorder_addon".to_string(),
+                    Some("sinks".to_string()),
+                    None,
+                    None,
+                ),
+                GraphNode::new_selector_node(
+                    "sinks".to_string(),
+                    SelectorExpr::AnyOf {
+                        any_of: vec![
+                            SelectorExpr::Predicate(SelectorPredicate {
+                                addon: Some("logger_addon".to_string()),
+                                app: None,
+                            }),
+                            SelectorExpr::Predicate(SelectorPredicate {
+                                addon: Some("recorder_addon".to_string()),
+                                app: None,
+                            }),
+                        ],
+                    },
+                ),
+            ],
+            connections: Some(vec![GraphConnection {
+                loc: GraphLoc {
+                    extension: Some("tts".to_string()),
+                    app: None,
+                    subgraph: None,
+                    selector: None,
+                },
+                cmd: Some(vec![GraphMessageFlow::new(
+                    Some("tts_complete".to_string()),
+                    None,
+                    vec![GraphDestination {
+                        loc: GraphLoc {
+                            extension: None,
+                            app: None,
+                            subgraph: None,
+                            selector: Some("sinks".to_string()),
+                        },
+                        msg_conversion: None,
+                    }],
+                    vec![],
+                )]),
+                data: None,
+                audio_frame: None,
+                video_frame: None,
+            }]),
+            exposed_messages: None,
+            exposed_properties: None,
+        }
+    }
+
+    #[test]
+    fn test_resolve_selectors_fans_out_destination() {
+        let graph = sinks_selector_graph();
+        let resolved = graph.resolve_selectors().unwrap();
+
+        let connections = resolved.connections.unwrap();
+        assert_eq!(connections.len(), 1);
+
+        let cmd_flows = connections[0].cmd.as_ref().unwrap();
+        assert_eq!(cmd_flows.len(), 1);
+
+        let mut dest_names: Vec<&str> =
+            cmd_flows[0].dest.iter().map(|d| d.loc.extension.as_deref().unwrap()).collect();
+        dest_names.sort_unstable();
+        assert_eq!(dest_names, vec!["logger", "recorder"]);
+    }
+
+    #[test]
+    fn test_resolve_selectors_errors_on_zero_matches() {
+        let mut graph = sinks_selector_graph();
+        graph.nodes.retain(|n| n.get_name() != "logger" && n.get_name() != "recorder");
+
+        let err = graph.resolve_selectors().unwrap_err();
+        assert!(err.to_string().contains("matched zero nodes"));
+    }
+
+    #[test]
+    fn test_resolve_selectors_leaves_literal_locs_unchanged() {
+        let graph = Graph {
+            nodes: vec![
+                GraphNode::new_extension_node("a".to_string(), "a_addon".to_string(), None, None, None),
+                GraphNode::new_extension_node("b".to_string(), "b_addon".to_string(), None, None, None),
+            ],
+            connections: Some(vec![GraphConnection {
+                loc: GraphLoc {
+                    extension: Some("a".to_string()),
+                    app: None,
+                    subgraph: None,
+                    selector: None,
+                },
+                cmd: Some(vec![GraphMessageFlow::new(
+                    Some("hello".to_string()),
+                    None,
+                    vec![GraphDestination {
+                        loc: GraphLoc {
+                            extension: Some("b".to_string()),
+                            app: None,
+                            subgraph: None,
+                            selector: None,
+                        },
+                        msg_conversion: None,
+                    }],
+                    vec![GraphSource {
+                        loc: GraphLoc {
+                            extension: Some("a".to_string()),
+                            app: None,
+                            subgraph: None,
+                            selector: None,
+                        },
+                    }],
+                )]),
+                data: None,
+                audio_frame: None,
+                video_frame: None,
+            }]),
+            exposed_messages: None,
+            exposed_properties: None,
+        };
+
+        let resolved = graph.resolve_selectors().unwrap();
+        let connections = resolved.connections.unwrap();
+        assert_eq!(connections[0].cmd.as_ref().unwrap()[0].dest[0].loc.extension, Some("b".to_string()));
+    }
+}
@@ -0,0 +1,286 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+#[cfg(test)]
+mod tests {
+    use ten_rust::graph::{
+        connection::{GraphConnection, GraphDestination, GraphLoc, GraphMessageFlow},
+        node::GraphNode,
+        Graph,
+    };
+
+    fn sample_graph() -> Graph {
+        Graph {
+            nodes: vec![
+                GraphNode::new_extension_node(
+                    "ext_a".to_string(),
+                    "addon_a".to_string(),
+                    None,
+                    None,
+                    None,
+                ),
+                GraphNode::new_extension_node(
+                    "ext_b".to_string(),
+                    "addon_b".to_string(),
+                    None,
+                    None,
+                    None,
+                ),
+            ],
+            connections: None,
+            exposed_messages: None,
+            exposed_properties: None,
+        }
+    }
+
+    fn connection_from(src_ext: &str, msg_name: &str, dest_ext: &str) -> GraphConnection {
+        GraphConnection {
+            loc: GraphLoc {
+                app: None,
+                extension: Some(src_ext.to_string()),
+                subgraph: None,
+                selector: None,
+            },
+            cmd: Some(vec![GraphMessageFlow::new(
+                Some(msg_name.to_string()),
+                None,
+                vec![GraphDestination {
+                    loc: GraphLoc {
+                        app: None,
+                        extension: Some(dest_ext.to_string()),
+                        subgraph: None,
+                        selector: None,
+                    },
+                    msg_conversion: None,
+                }],
+                vec![],
+            )]),
+            data: None,
+            audio_frame: None,
+            video_frame: None,
+        }
+    }
+
+    #[test]
+    fn test_add_node_commits_on_success() {
+        let mut graph = sample_graph();
+
+        {
+            let mut txn = graph.begin();
+            txn.add_node(GraphNode::new_extension_node(
+                "ext_c".to_string(),
+                "addon_c".to_string(),
+                None,
+                None,
+                None,
+            ))
+            .unwrap();
+            txn.commit().unwrap();
+        }
+
+        assert_eq!(graph.nodes.len(), 3);
+    }
+
+    #[test]
+    fn test_remove_node_rolls_back_on_explicit_rollback() {
+        let mut graph = sample_graph();
+
+        {
+            let mut txn = graph.begin();
+            txn.remove_node(GraphLoc {
+                app: None,
+                extension: Some("ext_a".to_string()),
+                subgraph: None,
+                selector: None,
+            })
+            .unwrap();
+            txn.rollback();
+        }
+
+        assert_eq!(graph.nodes.len(), 2);
+    }
+
+    #[test]
+    fn test_remove_nonexistent_node_errors_without_partial_mutation() {
+        let mut graph = sample_graph();
+
+        let mut txn = graph.begin();
+        let result = txn.remove_node(GraphLoc {
+            app: None,
+            extension: Some("does_not_exist".to_string()),
+            subgraph: None,
+            selector: None,
+        });
+        assert!(result.is_err());
+
+        txn.rollback();
+        assert_eq!(graph.nodes.len(), 2);
+    }
+
+    #[test]
+    fn test_add_connection_commits_on_success() {
+        let mut graph = sample_graph();
+
+        {
+            let mut txn = graph.begin();
+            txn.add_connection(connection_from("ext_a", "hello", "ext_b")).unwrap();
+            txn.commit().unwrap();
+        }
+
+        assert_eq!(graph.connections.as_ref().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_remove_connection_with_duplicate_loc_only_removes_one() {
+        let mut graph = sample_graph();
+        graph.connections = Some(vec![
+            connection_from("ext_a", "hello", "ext_b"),
+            connection_from("ext_a", "world", "ext_b"),
+        ]);
+
+        let src = GraphLoc {
+            app: None,
+            extension: Some("ext_a".to_string()),
+            subgraph: None,
+            selector: None,
+        };
+
+        {
+            let mut txn = graph.begin();
+            txn.remove_connection(src).unwrap();
+            txn.commit().unwrap();
+        }
+
+        let remaining = graph.connections.as_ref().unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].cmd.as_ref().unwrap()[0].name.as_deref(), Some("world"));
+    }
+
+    #[test]
+    fn test_rollback_after_add_with_existing_duplicate_loc_keeps_original() {
+        // A connection already exists at `loc=ext_a` before the transaction
+        // begins; adding a second connection at the same `loc` and then
+        // rolling back must undo only the connection this transaction
+        // added, not the pre-existing one sharing its `loc`.
+        let mut graph = sample_graph();
+        graph.connections = Some(vec![connection_from("ext_a", "hello", "ext_b")]);
+
+        {
+            let mut txn = graph.begin();
+            txn.add_connection(connection_from("ext_a", "world", "ext_b")).unwrap();
+            txn.rollback();
+        }
+
+        let remaining = graph.connections.as_ref().unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].cmd.as_ref().unwrap()[0].name.as_deref(), Some("hello"));
+    }
+
+    #[test]
+    fn test_rollback_restores_original_order_when_add_and_remove_elsewhere_interleave() {
+        // Regression test: an `AddConnection`'s inverse used to be a raw
+        // index into the connections vec, which drifted as soon as a later
+        // op (here, removing a different connection) shifted positions.
+        // Replaying both inverses in LIFO order ended up losing the removed
+        // entry and keeping the added one. With three connections [A, B, C],
+        // adding X and then removing B (at index 1) must, on rollback,
+        // restore exactly [A, B, C] -- not [A, C, X].
+        let mut graph = sample_graph();
+        graph.connections = Some(vec![
+            connection_from("ext_a", "from_a", "ext_b"),
+            connection_from("ext_b", "from_b", "ext_a"),
+            connection_from("ext_a", "from_c", "ext_b"),
+        ]);
+
+        let b_loc = GraphLoc {
+            app: None,
+            extension: Some("ext_b".to_string()),
+            subgraph: None,
+            selector: None,
+        };
+
+        {
+            let mut txn = graph.begin();
+            txn.add_connection(connection_from("ext_a", "from_x", "ext_b")).unwrap();
+            txn.remove_connection(b_loc).unwrap();
+            txn.rollback();
+        }
+
+        let restored = graph.connections.as_ref().unwrap();
+        let names: Vec<&str> =
+            restored.iter().map(|conn| conn.cmd.as_ref().unwrap()[0].name.as_deref().unwrap()).collect();
+        assert_eq!(names, vec!["from_a", "from_b", "from_c"]);
+    }
+
+    #[test]
+    fn test_set_msg_conversion_commits_on_success() {
+        let mut graph = sample_graph();
+        graph.connections = Some(vec![connection_from("ext_a", "hello", "ext_b")]);
+
+        let src = GraphLoc {
+            app: None,
+            extension: Some("ext_a".to_string()),
+            subgraph: None,
+            selector: None,
+        };
+        let dest = GraphLoc {
+            app: None,
+            extension: Some("ext_b".to_string()),
+            subgraph: None,
+            selector: None,
+        };
+
+        {
+            let mut txn = graph.begin();
+            txn.set_msg_conversion(
+                src,
+                ten_rust::pkg_info::message::MsgType::Cmd,
+                "hello".to_string(),
+                dest,
+                None,
+            )
+            .unwrap();
+            txn.commit().unwrap();
+        }
+
+        let connections = graph.connections.as_ref().unwrap();
+        assert!(connections[0].cmd.as_ref().unwrap()[0].dest[0].msg_conversion.is_none());
+    }
+
+    #[test]
+    fn test_set_msg_conversion_on_missing_flow_errors_without_partial_mutation() {
+        let mut graph = sample_graph();
+        graph.connections = Some(vec![connection_from("ext_a", "hello", "ext_b")]);
+
+        let src = GraphLoc {
+            app: None,
+            extension: Some("ext_a".to_string()),
+            subgraph: None,
+            selector: None,
+        };
+        let dest = GraphLoc {
+            app: None,
+            extension: Some("ext_b".to_string()),
+            subgraph: None,
+            selector: None,
+        };
+
+        let mut txn = graph.begin();
+        let result = txn.set_msg_conversion(
+            src,
+            ten_rust::pkg_info::message::MsgType::Cmd,
+            "does_not_exist".to_string(),
+            dest,
+            None,
+        );
+        assert!(result.is_err());
+
+        txn.rollback();
+        let connections = graph.connections.as_ref().unwrap();
+        assert_eq!(connections.len(), 1);
+        assert_eq!(connections[0].cmd.as_ref().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,188 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+#[cfg(test)]
+mod tests {
+    use ten_rust::graph::{
+        connection::{GraphConnection, GraphDestination, GraphLoc, GraphMessageFlow},
+        node::GraphNode,
+        Graph,
+    };
+
+    #[test]
+    fn test_to_triples_emits_node_and_edge_triples() {
+        let graph = Graph {
+            nodes: vec![
+                GraphNode::new_extension_node(
+                    "test_extension_1".to_string(),
+                    "addon_1".to_string(),
+                    None,
+                    Some("http://app-x".to_string()),
+                    None,
+                ),
+                GraphNode::new_extension_node(
+                    "test_extension_3".to_string(),
+                    "addon_3".to_string(),
+                    None,
+                    Some("http://app-x".to_string()),
+                    None,
+                ),
+            ],
+            connections: Some(vec![GraphConnection {
+                loc: GraphLoc {
+                    extension: Some("test_extension_3".to_string()),
+                    app: None,
+                    subgraph: None,
+                    selector: None,
+                },
+                cmd: Some(vec![GraphMessageFlow::new(
+                    Some("hello_world".to_string()),
+                    None,
+                    vec![GraphDestination {
+                        loc: GraphLoc {
+                            extension: Some("test_extension_1".to_string()),
+                            app: None,
+                            subgraph: None,
+                            selector: None,
+                        },
+                        msg_conversion: None,
+                    }],
+                    vec![],
+                )]),
+                data: None,
+                audio_frame: None,
+                video_frame: None,
+            }]),
+            exposed_messages: None,
+            exposed_properties: None,
+        };
+
+        let triples = graph.to_triples();
+
+        assert!(triples.contains("<ext:test_extension_3> <flow:cmd> <ext:test_extension_1> ."));
+        assert!(triples.contains("<ext:test_extension_1> <app:uri> \"http://app-x\" ."));
+        assert!(triples.contains("<ext:test_extension_3> <addon:name> \"addon_3\" ."));
+        assert!(triples.contains("<edge:cmd:test_extension_3:test_extension_1:0> <flow:name> \"hello_world\" ."));
+    }
+
+    #[test]
+    fn test_to_triples_emits_every_name_in_a_names_list() {
+        let graph = Graph {
+            nodes: vec![
+                GraphNode::new_extension_node("src".to_string(), "src_addon".to_string(), None, None, None),
+                GraphNode::new_extension_node("dest".to_string(), "dest_addon".to_string(), None, None, None),
+            ],
+            connections: Some(vec![GraphConnection {
+                loc: GraphLoc {
+                    extension: Some("src".to_string()),
+                    app: None,
+                    subgraph: None,
+                    selector: None,
+                },
+                cmd: Some(vec![GraphMessageFlow::new(
+                    None,
+                    Some(vec!["cmd_a".to_string(), "cmd_b".to_string()]),
+                    vec![GraphDestination {
+                        loc: GraphLoc {
+                            extension: Some("dest".to_string()),
+                            app: None,
+                            subgraph: None,
+                            selector: None,
+                        },
+                        msg_conversion: None,
+                    }],
+                    vec![],
+                )]),
+                data: None,
+                audio_frame: None,
+                video_frame: None,
+            }]),
+            exposed_messages: None,
+            exposed_properties: None,
+        };
+
+        let triples = graph.to_triples();
+
+        let edge = "edge:cmd:src:dest:0";
+        assert!(triples.contains(&format!("<{}> <flow:name> \"cmd_a\" .", edge)));
+        assert!(triples.contains(&format!("<{}> <flow:name> \"cmd_b\" .", edge)));
+        assert!(triples.contains(&format!("<{}> <edge:from> <ext:src> .", edge)));
+        assert!(triples.contains(&format!("<{}> <edge:to> <ext:dest> .", edge)));
+    }
+
+    #[test]
+    fn test_to_triples_keeps_differently_named_flows_into_the_same_destination_distinct() {
+        let graph = Graph {
+            nodes: vec![
+                GraphNode::new_extension_node("src_a".to_string(), "addon".to_string(), None, None, None),
+                GraphNode::new_extension_node("src_b".to_string(), "addon".to_string(), None, None, None),
+                GraphNode::new_extension_node("dest".to_string(), "addon".to_string(), None, None, None),
+            ],
+            connections: Some(vec![
+                GraphConnection {
+                    loc: GraphLoc {
+                        extension: Some("src_a".to_string()),
+                        app: None,
+                        subgraph: None,
+                        selector: None,
+                    },
+                    cmd: Some(vec![GraphMessageFlow::new(
+                        Some("from_a".to_string()),
+                        None,
+                        vec![GraphDestination {
+                            loc: GraphLoc {
+                                extension: Some("dest".to_string()),
+                                app: None,
+                                subgraph: None,
+                                selector: None,
+                            },
+                            msg_conversion: None,
+                        }],
+                        vec![],
+                    )]),
+                    data: None,
+                    audio_frame: None,
+                    video_frame: None,
+                },
+                GraphConnection {
+                    loc: GraphLoc {
+                        extension: Some("src_b".to_string()),
+                        app: None,
+                        subgraph: None,
+                        selector: None,
+                    },
+                    cmd: Some(vec![GraphMessageFlow::new(
+                        Some("from_b".to_string()),
+                        None,
+                        vec![GraphDestination {
+                            loc: GraphLoc {
+                                extension: Some("dest".to_string()),
+                                app: None,
+                                subgraph: None,
+                                selector: None,
+                            },
+                            msg_conversion: None,
+                        }],
+                        vec![],
+                    )]),
+                    data: None,
+                    audio_frame: None,
+                    video_frame: None,
+                },
+            ]),
+            exposed_messages: None,
+            exposed_properties: None,
+        };
+
+        let triples = graph.to_triples();
+
+        assert!(triples.contains("<edge:cmd:src_a:dest:0> <flow:name> \"from_a\" ."));
+        assert!(triples.contains("<edge:cmd:src_b:dest:0> <flow:name> \"from_b\" ."));
+        // Neither edge's name triple bleeds onto the other's.
+        assert!(!triples.contains("<edge:cmd:src_a:dest:0> <flow:name> \"from_b\" ."));
+        assert!(!triples.contains("<edge:cmd:src_b:dest:0> <flow:name> \"from_a\" ."));
+    }
+}
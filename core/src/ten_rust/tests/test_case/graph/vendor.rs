@@ -0,0 +1,41 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use ten_rust::graph::vendor::{preview_relative_import_path, preview_vendored_path};
+
+    #[test]
+    fn test_preview_vendored_path_keys_by_host_and_path() {
+        let path = preview_vendored_path("https://example.com/pkgs/shared/foo.json").unwrap();
+        assert_eq!(path, std::path::PathBuf::from("example.com/pkgs/shared/foo.json"));
+    }
+
+    #[test]
+    fn test_preview_vendored_path_rejects_non_url() {
+        assert!(preview_vendored_path("not-a-url").is_err());
+    }
+
+    #[test]
+    fn test_relative_import_path_same_directory() {
+        let path = preview_relative_import_path(
+            &PathBuf::from("example.com/a/root.json"),
+            &PathBuf::from("example.com/a/child.json"),
+        );
+        assert_eq!(path, PathBuf::from("child.json"));
+    }
+
+    #[test]
+    fn test_relative_import_path_climbs_to_sibling_host() {
+        let path = preview_relative_import_path(
+            &PathBuf::from("example.com/a/root.json"),
+            &PathBuf::from("other.com/b/child.json"),
+        );
+        assert_eq!(path, PathBuf::from("../../other.com/b/child.json"));
+    }
+}
@@ -0,0 +1,44 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+#[cfg(test)]
+mod tests {
+    use ten_rust::utils::{data_uri::parse_data_uri, path::get_real_path_from_import_uri};
+
+    #[test]
+    fn test_parse_base64_payload() {
+        // `echo -n '{"a":1}' | base64` => "eyJhIjoxfQ=="
+        let (media_type, bytes) = parse_data_uri("data:application/json;base64,eyJhIjoxfQ==").unwrap();
+        assert_eq!(media_type, "application/json");
+        assert_eq!(bytes, b"{\"a\":1}");
+    }
+
+    #[test]
+    fn test_parse_percent_encoded_payload() {
+        let (media_type, bytes) = parse_data_uri("data:application/json,%7B%22a%22%3A1%7D").unwrap();
+        assert_eq!(media_type, "application/json");
+        assert_eq!(bytes, b"{\"a\":1}");
+    }
+
+    #[test]
+    fn test_missing_media_type_defaults_to_text_plain() {
+        let (media_type, bytes) = parse_data_uri("data:,hello").unwrap();
+        assert_eq!(media_type, "text/plain;charset=US-ASCII");
+        assert_eq!(bytes, b"hello");
+    }
+
+    #[test]
+    fn test_rejects_non_data_uri() {
+        assert!(parse_data_uri("file:///tmp/graph.json").is_err());
+    }
+
+    #[test]
+    fn test_get_real_path_from_import_uri_passes_data_uri_through_unchanged() {
+        let uri = "data:application/json;base64,eyJhIjoxfQ==";
+        let resolved = get_real_path_from_import_uri(uri, None, None).unwrap();
+        assert_eq!(resolved, uri);
+    }
+}
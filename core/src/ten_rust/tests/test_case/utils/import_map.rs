@@ -0,0 +1,63 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+#[cfg(test)]
+mod tests {
+    use ten_rust::utils::path::{resolve_with_import_map, ImportMap};
+
+    #[test]
+    fn test_exact_specifier_remap() {
+        let map: ImportMap = serde_json::from_value(serde_json::json!({
+            "imports": {
+                "shared/foo.json": "file:///pkgs/shared/foo.json"
+            }
+        }))
+        .unwrap();
+
+        let resolved = resolve_with_import_map("shared/foo.json", None, None, &map).unwrap();
+        assert_eq!(resolved, "file:///pkgs/shared/foo.json");
+    }
+
+    #[test]
+    fn test_trailing_slash_prefix_remap() {
+        let map: ImportMap = serde_json::from_value(serde_json::json!({
+            "imports": {
+                "shared/": "file:///pkgs/shared/"
+            }
+        }))
+        .unwrap();
+
+        let resolved = resolve_with_import_map("shared/foo.json", None, None, &map).unwrap();
+        assert_eq!(resolved, "file:///pkgs/shared/foo.json");
+    }
+
+    #[test]
+    fn test_most_specific_scope_wins() {
+        let map: ImportMap = serde_json::from_value(serde_json::json!({
+            "imports": {
+                "shared/": "file:///default/shared/"
+            },
+            "scopes": {
+                "/home/user/app": {
+                    "shared/": "file:///home/user/app/vendor/shared/"
+                }
+            }
+        }))
+        .unwrap();
+
+        let resolved =
+            resolve_with_import_map("shared/foo.json", Some("/home/user/app"), None, &map).unwrap();
+        assert_eq!(resolved, "file:///home/user/app/vendor/shared/foo.json");
+    }
+
+    #[test]
+    fn test_no_match_falls_through_to_relative_resolution() {
+        let map = ImportMap::default();
+        let resolved =
+            resolve_with_import_map("foo.json", Some("/home/user/app"), None, &map).unwrap();
+        assert_eq!(resolved, "/home/user/app/foo.json");
+    }
+}
@@ -0,0 +1,57 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+#[cfg(test)]
+mod tests {
+    use ten_rust::utils::path::{get_real_path_from_import_uri_with_policy, ImportPolicy};
+
+    #[test]
+    fn test_denies_http_host_not_in_allowlist() {
+        let policy = ImportPolicy::new();
+        let result = get_real_path_from_import_uri_with_policy(
+            "http://evil.example.com/graph.json",
+            None,
+            None,
+            &policy,
+        );
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("is not permitted"));
+    }
+
+    #[test]
+    fn test_allows_whitelisted_host() {
+        let mut policy = ImportPolicy::new();
+        policy.allow_host("trusted.example.com:80");
+
+        let result = get_real_path_from_import_uri_with_policy(
+            "http://trusted.example.com/graph.json",
+            None,
+            None,
+            &policy,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_allows_file_uri_by_default() {
+        let policy = ImportPolicy::new();
+        let result =
+            get_real_path_from_import_uri_with_policy("file:///tmp/graph.json", None, None, &policy);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_allows_relative_path_by_default() {
+        let policy = ImportPolicy::new();
+        let result = get_real_path_from_import_uri_with_policy(
+            "graph.json",
+            Some("/home/user/app"),
+            None,
+            &policy,
+        );
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,62 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+#[cfg(test)]
+mod tests {
+    use ten_rust::utils::path::{resolve_import_uri, ImportMap, ImportPolicy};
+
+    #[test]
+    fn test_no_map_no_policy_resolves_like_plain_get_real_path() {
+        let resolved =
+            resolve_import_uri("foo.json", Some("/home/user/app"), None, None, None).unwrap();
+        assert_eq!(resolved, "/home/user/app/foo.json");
+    }
+
+    #[test]
+    fn test_import_map_remap_is_applied_before_policy_check() {
+        let map: ImportMap = serde_json::from_value(serde_json::json!({
+            "imports": {
+                "shared/": "http://trusted.example.com/"
+            }
+        }))
+        .unwrap();
+        let mut policy = ImportPolicy::new();
+        policy.allow_host("trusted.example.com:80");
+
+        let resolved =
+            resolve_import_uri("shared/foo.json", None, None, Some(&map), Some(&policy)).unwrap();
+        assert_eq!(resolved, "http://trusted.example.com/foo.json");
+    }
+
+    #[test]
+    fn test_policy_denies_a_host_the_import_map_remapped_to() {
+        let map: ImportMap = serde_json::from_value(serde_json::json!({
+            "imports": {
+                "shared/": "http://evil.example.com/"
+            }
+        }))
+        .unwrap();
+        let policy = ImportPolicy::new();
+
+        let result = resolve_import_uri("shared/foo.json", None, None, Some(&map), Some(&policy));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("is not permitted"));
+    }
+
+    #[test]
+    fn test_no_policy_allows_whatever_the_import_map_remaps_to() {
+        let map: ImportMap = serde_json::from_value(serde_json::json!({
+            "imports": {
+                "shared/": "http://evil.example.com/"
+            }
+        }))
+        .unwrap();
+
+        let resolved =
+            resolve_import_uri("shared/foo.json", None, None, Some(&map), None).unwrap();
+        assert_eq!(resolved, "http://evil.example.com/foo.json");
+    }
+}
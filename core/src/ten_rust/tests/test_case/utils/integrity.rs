@@ -0,0 +1,37 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+#[cfg(test)]
+mod tests {
+    use ten_rust::utils::integrity::IntegrityLockfile;
+
+    #[test]
+    fn test_unlocked_uri_gets_hash_recorded() {
+        let mut lock = IntegrityLockfile::default();
+        lock.verify_or_record("file:///tmp/graph.json", b"content").unwrap();
+        assert!(lock.entries.contains_key("file:///tmp/graph.json"));
+        assert!(lock.entries["file:///tmp/graph.json"].starts_with("sha256-"));
+    }
+
+    #[test]
+    fn test_matching_content_passes() {
+        let mut lock = IntegrityLockfile::default();
+        lock.verify_or_record("file:///tmp/graph.json", b"content").unwrap();
+        assert!(lock.verify_or_record("file:///tmp/graph.json", b"content").is_ok());
+    }
+
+    #[test]
+    fn test_changed_content_is_a_hard_error() {
+        let mut lock = IntegrityLockfile::default();
+        lock.verify_or_record("file:///tmp/graph.json", b"content").unwrap();
+
+        let result = lock.verify_or_record("file:///tmp/graph.json", b"different content");
+        assert!(result.is_err());
+        let err_msg = result.unwrap_err().to_string();
+        assert!(err_msg.contains("file:///tmp/graph.json"));
+        assert!(err_msg.contains("expected"));
+    }
+}
@@ -0,0 +1,39 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+#[cfg(test)]
+mod tests {
+    use ten_rust::utils::path::{get_real_path_from_import_uri, ModuleResolutionError};
+
+    #[test]
+    fn test_missing_base_dir_is_structured_error() {
+        let result = get_real_path_from_import_uri("interface.json", None, None);
+        assert!(result.is_err());
+
+        let err = result.unwrap_err();
+        let resolution_err = err.downcast_ref::<ModuleResolutionError>();
+        assert!(resolution_err.is_some());
+        assert!(err.to_string().contains("interface.json"));
+    }
+
+    #[test]
+    fn test_chain_frame_is_appended_to_display() {
+        let err = get_real_path_from_import_uri("interface.json", None, None).unwrap_err();
+        let resolution_err = err.downcast::<ModuleResolutionError>().unwrap();
+
+        let decorated = resolution_err.with_chain_frame("subgraph 'audio' (file:///audio.json)");
+        let message = decorated.to_string();
+
+        assert!(message.contains("imported from subgraph 'audio' (file:///audio.json)"));
+    }
+
+    #[test]
+    fn test_absolute_path_is_rejected() {
+        let result = get_real_path_from_import_uri("/etc/passwd", None, None);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("absolute paths"));
+    }
+}